@@ -20,6 +20,10 @@ pub const COAL_PROGRAM_ID_BYTES: [u8; 32] = unsafe { *(&COAL_PROGRAM_ID as *cons
 pub const COAL_UPDATE_AUTHORITY_SEED: &[u8] = b"update_authority";
 pub const COAL_UPDATE_AUTHORITY: Pubkey = Pubkey::new_from_array(ed25519::derive_program_address(&[COAL_UPDATE_AUTHORITY_SEED], &COAL_PROGRAM_ID_BYTES).0);
 
+/// The COAL program pda allowed to use tools.
+pub const COAL_TOOL_AUTHORITY_SEED: &[u8] = b"tool_authority";
+pub const COAL_TOOL_AUTHORITY: Pubkey = Pubkey::new_from_array(ed25519::derive_program_address(&[COAL_TOOL_AUTHORITY_SEED], &COAL_PROGRAM_ID_BYTES).0);
+
 pub const ROYALTIES_BASIS_POINTS: u16 = 400;
 pub const ROYALTY_CREATOR_ADDRESS: Pubkey = pubkey!("B7yXtWpKXfwLDGyHLvab7ypZemajAbR2Kvbn2ogNs8J9");
 
@@ -28,6 +32,72 @@ pub const COLLECTION_AUTHORITY_ADDRESS: Pubkey = Pubkey::new_from_array(ed25519:
 
 pub const CONFIG_SEED: &[u8] = b"config";
 
+/// The current layout version of config accounts. Bumped on every layout change. Fields are only
+/// ever appended, so configs of older versions migrate by zero-extending them.
+pub const CONFIG_VERSION: u64 = 1;
+
+/// The size of config accounts created before configs were versioned, which held a fixed list of
+/// three ingredients.
+pub const LEGACY_CONFIG_SIZE: usize = 8 + 120;
+
+pub const RECIPE_SEED: &[u8] = b"recipe";
+
+/// The current layout version of recipe accounts.
+pub const RECIPE_VERSION: u64 = 1;
+
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+
+pub const TOOL_SEED: &[u8] = b"tool";
+
+/// The recipe id of tools that were not crafted from a recipe of their collection.
+pub const NO_RECIPE: u64 = u64::MAX;
+
+/// The minimum number of tools consumed by a reforge.
+pub const MIN_UPGRADE_INPUTS: u64 = 2;
+
+/// The maximum number of tools consumed by a reforge.
+pub const MAX_UPGRADE_INPUTS: u64 = 8;
+
+pub const LOADOUT_SEED: &[u8] = b"loadout";
+
+/// The maximum number of resources a loadout holds a tool for.
+pub const MAX_LOADOUT_SLOTS: usize = 8;
+
+pub const RENTAL_SEED: &[u8] = b"rental";
+
+pub const COMMITMENT_SEED: &[u8] = b"commitment";
+
+/// The current layout version of commitment accounts. Bumped on every layout change.
+pub const COMMITMENT_VERSION: u64 = 1;
+
+/// The number of slots between committing a craft and the slot its attributes are rolled from.
+pub const REVEAL_DELAY_SLOTS: u64 = 2;
+
+/// The number of slots after the target slot a committed craft can be revealed in. Kept well
+/// within the history of the SlotHashes sysvar.
+pub const REVEAL_WINDOW_SLOTS: u64 = 256;
+
+/// Basis points denominating a whole.
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+/// The maximum number of ingredients a recipe may hold.
+pub const MAX_INGREDIENTS: usize = 8;
+
+/// The maximum number of rarity tiers a collection may define.
+pub const MAX_RARITY_TIERS: usize = 8;
+
+/// The maximum number of enchantments a collection may define.
+pub const MAX_ENCHANTMENTS: usize = 8;
+
+/// The attributes enchantments cannot raise.
+pub const RESERVED_ATTRIBUTES: [&str; 3] = ["rarity", "resource", "durability"];
+
+/// The maximum length in bytes of a tool name, rarity or resource.
+pub const MAX_NAME_LEN: usize = 32;
+
+/// The maximum length in bytes of a tool metadata uri.
+pub const MAX_URI_LEN: usize = 128;
+
 /// Mints
 pub const COAL_MINT_ADDRESS: Pubkey = pubkey!("E3yUqBNTZxV8ELvW99oRLC7z4ddbJqqR4NphwrMug9zu");
 pub const INGOT_MINT_ADDEESS: Pubkey = pubkey!("7W6R9rG1kfadLBUWw4mAj9eRCmARtzkbttKVdawVx15V");
@@ -9,6 +9,72 @@ pub enum ForgeError {
     AuthFailed = 7,
     #[error("Invalid resource")]
     InvalidResource = 8,
+    #[error("Invalid ingredients")]
+    InvalidIngredients = 9,
+    #[error("Invalid metadata")]
+    InvalidMetadata = 10,
+    #[error("Max supply reached")]
+    MaxSupplyReached = 11,
+    #[error("Crafting has not started")]
+    CraftingNotStarted = 12,
+    #[error("Crafting has ended")]
+    CraftingEnded = 13,
+    #[error("Invalid crafting window")]
+    InvalidWindow = 14,
+    #[error("Wallet mint limit reached")]
+    WalletLimitReached = 15,
+    #[error("Wallet is not allowlisted")]
+    NotAllowlisted = 16,
+    #[error("Invalid auction")]
+    InvalidAuction = 17,
+    #[error("Invalid rarity tiers")]
+    InvalidRarityTiers = 18,
+    #[error("Commitment is still pending")]
+    CommitmentPending = 19,
+    #[error("Commitment cannot be revealed yet")]
+    CommitmentNotReady = 20,
+    #[error("Commitment has expired")]
+    CommitmentExpired = 21,
+    #[error("Collection is retired")]
+    CollectionRetired = 24,
+    #[error("Tool is broken")]
+    ToolBroken = 25,
+    #[error("Invalid recipe")]
+    InvalidRecipe = 26,
+    #[error("Tool cannot be repaired")]
+    NotRepairable = 27,
+    #[error("Tool cannot be dismantled")]
+    NotSalvageable = 28,
+    #[error("Invalid upgrade path")]
+    InvalidUpgrade = 29,
+    #[error("Tool cannot be reforged")]
+    NotUpgradeable = 30,
+    #[error("Invalid enchantments")]
+    InvalidEnchantments = 31,
+    #[error("Tool has no enchant slots left")]
+    NoEnchantSlots = 32,
+    #[error("Enchantment is maxed")]
+    EnchantmentMaxed = 33,
+    #[error("Loadout is full")]
+    LoadoutFull = 34,
+    #[error("A tool is already equipped for the resource")]
+    ResourceEquipped = 35,
+    #[error("Tool is not equipped")]
+    NotEquipped = 36,
+    #[error("Invalid rental")]
+    InvalidRental = 37,
+    #[error("Tool is already rented")]
+    AlreadyRented = 38,
+    #[error("Rental has not expired")]
+    RentalActive = 39,
+    #[error("Tool cannot be reaped")]
+    NotReapable = 40,
+    #[error("Invalid config")]
+    InvalidConfig = 41,
+    #[error("Collection must be crafted through commit and reveal")]
+    CommitRequired = 42,
+    #[error("Rental has expired")]
+    RentalExpired = 43,
 }
 
 impl From<ForgeError> for ProgramError {
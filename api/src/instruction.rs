@@ -2,19 +2,118 @@ use std::io::Error;
 use std::mem::size_of;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    instruction::{AccountMeta, Instruction}, pubkey::Pubkey, system_program,
+    instruction::{AccountMeta, Instruction}, pubkey::Pubkey, system_program, sysvar,
 };
 use mpl_core::programs::MPL_CORE_ID;
 
-use crate::consts::*;
+use crate::{consts::*, pricing::Curve, state::{Enchantment, Ingredient, RarityTier}};
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct MintV1Args {
-    pub resource: String,
+    pub recipe_id: u64,
+    pub proof: Vec<[u8; 32]>,
     pub config_bump: u8,
+    pub receipt_bump: u8,
+    pub tool_bump: u8,
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct CommitCraftV1Args {
+    pub recipe_id: u64,
+    pub proof: Vec<[u8; 32]>,
+    pub receipt_bump: u8,
+    pub commitment_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RevealCraftV1Args {
+    pub tool_bump: u8,
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ForfeitCommitmentV1Args {
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RepairV1Args {
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DismantleV1Args {}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReforgeV1Args {
+    pub proof: Vec<[u8; 32]>,
+    pub receipt_bump: u8,
+    pub tool_bump: u8,
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct EnchantV1Args {
+    pub enchantment: u64,
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct EquipV1Args {
+    pub loadout_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UnequipV1Args {
+    pub collection_authority_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ListToolV1Args {
+    pub price: u64,
+    pub duration: u64,
+    pub rental_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RentToolV1Args {
+    pub loadout_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReturnToolV1Args {
+    pub rental_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReapV1Args {
     pub collection_authority_bump: u8,
+}
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UseToolV1Args {
+    pub amount: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct InitToolV1Args {
+    pub tool_bump: u8,
 }
 
 #[repr(C)]
@@ -24,12 +123,96 @@ pub struct NewV1Args {
     pub uri: String,
     pub multiplier: u64,
     pub durability: u64,
-    pub ingredients: [Pubkey; 3],
-    pub amounts: [u64; 3],
+    pub rarity: String,
+    pub resource: String,
+    pub max_supply: u64,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub wallet_limit: u64,
+    pub merkle_root: [u8; 32],
+    pub fee: u64,
+    pub curve: Curve,
+    pub curve_rate: u64,
+    pub auction_start_basis_points: u64,
+    pub auction_floor_basis_points: u64,
+    pub auction_duration: i64,
+    pub rarity_tiers: Vec<RarityTier>,
+    pub soulbound: bool,
+    pub ingredients: Vec<Ingredient>,
     pub config_bump: u8,
+    pub recipe_bump: u8,
     pub collection_authority_bump: u8,
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UpdateConfigV1Args {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub multiplier: Option<u64>,
+    pub durability: Option<u64>,
+    pub rarity: Option<String>,
+    pub resource: Option<String>,
+    pub max_supply: Option<u64>,
+    pub start_at: Option<i64>,
+    pub end_at: Option<i64>,
+    pub wallet_limit: Option<u64>,
+    pub merkle_root: Option<[u8; 32]>,
+    pub fee: Option<u64>,
+    pub curve: Option<Curve>,
+    pub curve_rate: Option<u64>,
+    pub rarity_tiers: Option<Vec<RarityTier>>,
+    pub repair_recipe: Option<u64>,
+    pub repair_durability: Option<u64>,
+    pub salvage_basis_points: Option<u64>,
+    pub upgrade_collection: Option<Pubkey>,
+    pub upgrade_inputs: Option<u64>,
+    pub upgrade_recipe: Option<u64>,
+    pub enchant_slots: Option<u64>,
+    pub enchantments: Option<Vec<Enchantment>>,
+    pub soulbound: Option<bool>,
+    pub reap_reward: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct NewRecipeV1Args {
+    pub id: u64,
+    pub ingredients: Vec<Ingredient>,
+    pub recipe_bump: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UpdateRecipeV1Args {
+    pub id: u64,
+    pub ingredients: Vec<Ingredient>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct CloseConfigV1Args {}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UnfreezeV1Args {
+    pub collection_authority_bump: u8,
+}
+
+/// The tool metadata of a legacy config, which was not stored on chain. Ignored when migrating
+/// configs that are already versioned.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MigrateConfigV1Args {
+    pub name: String,
+    pub uri: String,
+    pub multiplier: u64,
+    pub durability: u64,
+    pub rarity: String,
+    pub resource: String,
+    pub recipe_bump: u8,
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct InitializeArgs {
@@ -51,6 +234,29 @@ pub enum ForgeInstruction {
     NewV1(NewV1Args),
     Initialize(InitializeArgs),
     Verify(VerifyArgs),
+    UpdateConfigV1(UpdateConfigV1Args),
+    NewRecipeV1(NewRecipeV1Args),
+    UpdateRecipeV1(UpdateRecipeV1Args),
+    CloseConfigV1(CloseConfigV1Args),
+    UnfreezeV1(UnfreezeV1Args),
+    MigrateConfigV1(MigrateConfigV1Args),
+    // User
+    CommitCraftV1(CommitCraftV1Args),
+    RevealCraftV1(RevealCraftV1Args),
+    ForfeitCommitmentV1(ForfeitCommitmentV1Args),
+    RepairV1(RepairV1Args),
+    DismantleV1(DismantleV1Args),
+    ReforgeV1(ReforgeV1Args),
+    EnchantV1(EnchantV1Args),
+    EquipV1(EquipV1Args),
+    UnequipV1(UnequipV1Args),
+    ListToolV1(ListToolV1Args),
+    RentToolV1(RentToolV1Args),
+    ReturnToolV1(ReturnToolV1Args),
+    ReapV1(ReapV1Args),
+    InitToolV1(InitToolV1Args),
+    // Coal
+    UseToolV1(UseToolV1Args),
 }
 
 impl ForgeInstruction {
@@ -61,6 +267,25 @@ impl ForgeInstruction {
     }
 }
 
+/// Returns the (mint, token account, treasury token account) triple of each ingredient, in order.
+pub fn ingredient_accounts(signer: Pubkey, mints: &[Pubkey]) -> Vec<AccountMeta> {
+    let mut accounts = vec![];
+    for mint in mints.iter() {
+        let ingredient_tokens = spl_associated_token_account::get_associated_token_address(
+            &signer,
+            mint,
+        );
+        let treasury_tokens = spl_associated_token_account::get_associated_token_address(
+            &TREASURY_ADDRESS,
+            mint,
+        );
+        accounts.push(AccountMeta::new(*mint, false));
+        accounts.push(AccountMeta::new(ingredient_tokens, false));
+        accounts.push(AccountMeta::new(treasury_tokens, false));
+    }
+    accounts
+}
+
 pub fn initialize(signer: Pubkey) -> Instruction {
     let initialize_args = ForgeInstruction::Initialize(InitializeArgs {
         treasury_bump: TREASURY_BUMP,
@@ -96,57 +321,274 @@ pub fn verify(signer: Pubkey, destination: Pubkey) -> Instruction {
     }
 }   
 
-/// Builds a new instruction.
-pub fn new(signer: Pubkey, collection: Pubkey) -> Instruction {
+/// Builds a new instruction. The collection is created with recipe 0 holding the ingredients
+/// in `args`. The bumps in `args` are replaced with the canonical ones.
+pub fn new(signer: Pubkey, collection: Pubkey, args: NewV1Args) -> Instruction {
     let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
     let (config, config_bump) = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id());
+    let (recipe, recipe_bump) = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &0u64.to_le_bytes()], &crate::id());
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(collection, true),
+        AccountMeta::new_readonly(collection_authority, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new(recipe, false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new(system_program::id(), false),
+    ];
+    for ingredient in args.ingredients.iter() {
+        accounts.push(AccountMeta::new_readonly(ingredient.mint, false));
+    }
 
     let new_v1_args = ForgeInstruction::NewV1(NewV1Args {
-        name: "Miner's Pickaxe".to_string(),
-        uri: "https://minechain.gg/metadata.pickaxe.json".to_string(),
-        multiplier: 70, // 70% bonus
-        durability: 1000, // 1000 uses
-        amounts: [ONE_TOKEN.saturating_mul(1), 0, 0],
-        ingredients: [COAL_MINT_ADDRESS, solana_program::system_program::ID, solana_program::system_program::ID],
         config_bump,
+        recipe_bump,
         collection_authority_bump,
+        ..args
     });
 
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [new_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+/// Builds an update config instruction.
+pub fn update_config(signer: Pubkey, collection: Pubkey, args: UpdateConfigV1Args) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+
+    let update_config_v1_args = ForgeInstruction::UpdateConfigV1(args);
+
     Instruction {
         program_id: crate::id(),
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(collection, true),
-            AccountMeta::new_readonly(collection_authority, false),
+            AccountMeta::new_readonly(collection, false),
             AccountMeta::new(config, false),
+        ],
+        data: [update_config_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+/// Builds a new recipe instruction. The id in `args` must be the collection's next recipe id.
+/// The bump in `args` is replaced with the canonical one.
+pub fn new_recipe(signer: Pubkey, collection: Pubkey, args: NewRecipeV1Args) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let (recipe, recipe_bump) = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &args.id.to_le_bytes()], &crate::id());
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(collection, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new(recipe, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for ingredient in args.ingredients.iter() {
+        accounts.push(AccountMeta::new_readonly(ingredient.mint, false));
+    }
+
+    let new_recipe_v1_args = ForgeInstruction::NewRecipeV1(NewRecipeV1Args {
+        recipe_bump,
+        ..args
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [new_recipe_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+/// Builds an update recipe instruction.
+pub fn update_recipe(signer: Pubkey, collection: Pubkey, args: UpdateRecipeV1Args) -> Instruction {
+    let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &args.id.to_le_bytes()], &crate::id()).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(collection, false),
+        AccountMeta::new(recipe, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for ingredient in args.ingredients.iter() {
+        accounts.push(AccountMeta::new_readonly(ingredient.mint, false));
+    }
+
+    let update_recipe_v1_args = ForgeInstruction::UpdateRecipeV1(args);
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [update_recipe_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+/// Builds a close config instruction. All recipes of the collection are closed along with it.
+pub fn close_config(signer: Pubkey, collection: Pubkey, destination: Pubkey, recipes: u64) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(collection, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new(destination, false),
+    ];
+    for id in 0..recipes {
+        let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &id.to_le_bytes()], &crate::id()).0;
+        accounts.push(AccountMeta::new(recipe, false));
+    }
+
+    let close_config_v1_args = ForgeInstruction::CloseConfigV1(CloseConfigV1Args {});
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [close_config_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, collection_info, config, recipe, system_program
+// followed by the mints of the legacy ingredients, in order, when migrating a legacy config.
+pub fn migrate_config(signer: Pubkey, collection: Pubkey, ingredients: Vec<Pubkey>, args: MigrateConfigV1Args) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let (recipe, recipe_bump) = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &0u64.to_le_bytes()], &crate::id());
+
+    let migrate_config_v1_args = ForgeInstruction::MigrateConfigV1(MigrateConfigV1Args {
+        recipe_bump,
+        ..args
+    });
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(collection, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new(recipe, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for ingredient in ingredients.iter() {
+        accounts.push(AccountMeta::new_readonly(*ingredient, false));
+    }
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [migrate_config_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, collection_info, collection_authority, mpl_core_program, system_program
+pub fn unfreeze(signer: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+
+    let unfreeze_v1_args = ForgeInstruction::UnfreezeV1(UnfreezeV1Args {
+        collection_authority_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(asset, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new_readonly(collection_authority, false),
             AccountMeta::new_readonly(MPL_CORE_ID, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
-            AccountMeta::new(system_program::id(), false),
-            AccountMeta::new_readonly(COAL_MINT_ADDRESS, false),
-            AccountMeta::new_readonly(WOOD_MINT_ADDRESS, false),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
-        data: [new_v1_args.try_to_vec().unwrap()].concat(),
+        data: [unfreeze_v1_args.try_to_vec().unwrap()].concat(),
     }
 }
 
-// signer, mint_info, collection_info, collection_authority, mpl_core_program, system_program
-pub fn mint(signer: Pubkey, collection: Pubkey, mint: Pubkey, resource: String) -> Instruction {
+// signer, mint_info, collection_info, collection_authority, config, recipe, receipt, tool, treasury, mpl_core_program, token_program, associated_token_program, system_program
+// followed by a (mint, token account, treasury token account) triple for each ingredient of the recipe, in order.
+// The proof is only checked for allowlisted collections and can be empty otherwise.
+pub fn mint(signer: Pubkey, collection: Pubkey, mint: Pubkey, recipe_id: u64, ingredients: Vec<Pubkey>, proof: Vec<[u8; 32]>) -> Instruction {
     let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
     let (config, config_bump) = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id());
-
-    let ingot_tokens = spl_associated_token_account::get_associated_token_address(
-        &signer,
-        &INGOT_MINT_ADDEESS,
-    );
-    let wood_tokens = spl_associated_token_account::get_associated_token_address(
-        &signer,
-        &WOOD_MINT_ADDRESS,
-    );
+    let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &recipe_id.to_le_bytes()], &crate::id()).0;
+    let (receipt, receipt_bump) = Pubkey::find_program_address(&[RECEIPT_SEED, collection.as_ref(), signer.as_ref()], &crate::id());
+    let (tool, tool_bump) = Pubkey::find_program_address(&[TOOL_SEED, mint.as_ref()], &crate::id());
 
     let mint_v1_args: ForgeInstruction = ForgeInstruction::MintV1(MintV1Args {
-        resource,
+        recipe_id,
+        proof,
         config_bump,
+        receipt_bump,
+        tool_bump,
+        collection_authority_bump,
+    });
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(mint, true),
+        AccountMeta::new(collection, false),
+        AccountMeta::new_readonly(collection_authority, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new_readonly(recipe, false),
+        AccountMeta::new(receipt, false),
+        AccountMeta::new(tool, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new(system_program::id(), false),
+    ];
+    accounts.extend(ingredient_accounts(signer, &ingredients));
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [mint_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, collection_info, config, recipe, receipt, commitment, treasury, token_program, associated_token_program, system_program
+// followed by a (mint, token account, treasury token account) triple for each ingredient of the recipe, in order.
+pub fn commit_craft(signer: Pubkey, collection: Pubkey, recipe_id: u64, ingredients: Vec<Pubkey>, proof: Vec<[u8; 32]>) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &recipe_id.to_le_bytes()], &crate::id()).0;
+    let (receipt, receipt_bump) = Pubkey::find_program_address(&[RECEIPT_SEED, collection.as_ref(), signer.as_ref()], &crate::id());
+    let (commitment, commitment_bump) = Pubkey::find_program_address(&[COMMITMENT_SEED, collection.as_ref(), signer.as_ref()], &crate::id());
+
+    let commit_craft_v1_args = ForgeInstruction::CommitCraftV1(CommitCraftV1Args {
+        recipe_id,
+        proof,
+        receipt_bump,
+        commitment_bump,
+    });
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(collection, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new_readonly(recipe, false),
+        AccountMeta::new(receipt, false),
+        AccountMeta::new(commitment, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(ingredient_accounts(signer, &ingredients));
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [commit_craft_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, mint_info, collection_info, collection_authority, config, commitment, tool, mpl_core_program, system_program, slot_hashes
+pub fn reveal_craft(signer: Pubkey, collection: Pubkey, mint: Pubkey) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let commitment = Pubkey::find_program_address(&[COMMITMENT_SEED, collection.as_ref(), signer.as_ref()], &crate::id()).0;
+    let (tool, tool_bump) = Pubkey::find_program_address(&[TOOL_SEED, mint.as_ref()], &crate::id());
+
+    let reveal_craft_v1_args = ForgeInstruction::RevealCraftV1(RevealCraftV1Args {
+        tool_bump,
         collection_authority_bump,
     });
 
@@ -157,15 +599,373 @@ pub fn mint(signer: Pubkey, collection: Pubkey, mint: Pubkey, resource: String)
             AccountMeta::new(mint, true),
             AccountMeta::new(collection, false),
             AccountMeta::new_readonly(collection_authority, false),
+            AccountMeta::new(config, false),
+            AccountMeta::new(commitment, false),
+            AccountMeta::new(tool, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+        ],
+        data: [reveal_craft_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, authority, collection_info, config, receipt, commitment
+pub fn forfeit_commitment(signer: Pubkey, authority: Pubkey, collection: Pubkey) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let receipt = Pubkey::find_program_address(&[RECEIPT_SEED, collection.as_ref(), authority.as_ref()], &crate::id()).0;
+    let commitment = Pubkey::find_program_address(&[COMMITMENT_SEED, collection.as_ref(), authority.as_ref()], &crate::id()).0;
+
+    let forfeit_commitment_v1_args = ForgeInstruction::ForfeitCommitmentV1(ForfeitCommitmentV1Args {});
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(collection, false),
+            AccountMeta::new(config, false),
+            AccountMeta::new(receipt, false),
+            AccountMeta::new(commitment, false),
+        ],
+        data: [forfeit_commitment_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, collection_authority, config, recipe, treasury, mpl_core_program, token_program, associated_token_program, system_program
+// followed by a (mint, token account, treasury token account) triple for each ingredient of the repair recipe, in order.
+pub fn repair(signer: Pubkey, collection: Pubkey, asset: Pubkey, recipe_id: u64, ingredients: Vec<Pubkey>) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &recipe_id.to_le_bytes()], &crate::id()).0;
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+
+    let repair_v1_args = ForgeInstruction::RepairV1(RepairV1Args {
+        collection_authority_bump,
+    });
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(asset, false),
+        AccountMeta::new(tool, false),
+        AccountMeta::new(collection, false),
+        AccountMeta::new_readonly(collection_authority, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(recipe, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(ingredient_accounts(signer, &ingredients));
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [repair_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, config, treasury, mpl_core_program, token_program, associated_token_program, system_program
+// followed by a (mint, token account, treasury token account) triple for each ingredient escrowed by the tool's craft, in order.
+pub fn dismantle(signer: Pubkey, collection: Pubkey, asset: Pubkey, ingredients: Vec<Pubkey>) -> Instruction {
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+
+    let dismantle_v1_args = ForgeInstruction::DismantleV1(DismantleV1Args {});
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(asset, false),
+        AccountMeta::new(tool, false),
+        AccountMeta::new(collection, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(ingredient_accounts(signer, &ingredients));
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [dismantle_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, mint_info, collection_info, config, recipe, target_collection, collection_authority, target_config, target_receipt, tool, treasury, mpl_core_program, token_program, associated_token_program, system_program
+// followed by an (asset, tool) pair for each tool consumed, and a (mint, token account, treasury token account) triple for each ingredient of the upgrade recipe, in order.
+// The proof is only checked if the target collection is allowlisted and can be empty otherwise.
+#[allow(clippy::too_many_arguments)]
+pub fn reforge(signer: Pubkey, collection: Pubkey, target_collection: Pubkey, mint: Pubkey, recipe_id: u64, assets: Vec<Pubkey>, ingredients: Vec<Pubkey>, proof: Vec<[u8; 32]>) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let recipe = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &recipe_id.to_le_bytes()], &crate::id()).0;
+    let target_config = Pubkey::find_program_address(&[CONFIG_SEED, target_collection.as_ref()], &crate::id()).0;
+    let (target_receipt, receipt_bump) = Pubkey::find_program_address(&[RECEIPT_SEED, target_collection.as_ref(), signer.as_ref()], &crate::id());
+    let (tool, tool_bump) = Pubkey::find_program_address(&[TOOL_SEED, mint.as_ref()], &crate::id());
+
+    let reforge_v1_args = ForgeInstruction::ReforgeV1(ReforgeV1Args {
+        proof,
+        receipt_bump,
+        tool_bump,
+        collection_authority_bump,
+    });
+
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(mint, true),
+        AccountMeta::new(collection, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(recipe, false),
+        AccountMeta::new(target_collection, false),
+        AccountMeta::new_readonly(collection_authority, false),
+        AccountMeta::new(target_config, false),
+        AccountMeta::new(target_receipt, false),
+        AccountMeta::new(tool, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(MPL_CORE_ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for asset in assets.iter() {
+        let asset_tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+        accounts.push(AccountMeta::new(*asset, false));
+        accounts.push(AccountMeta::new(asset_tool, false));
+    }
+    accounts.extend(ingredient_accounts(signer, &ingredients));
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [reforge_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, collection_authority, config, mint, token account, mpl_core_program, token_program, system_program
+// The mint is the one burned by the chosen enchantment of the collection.
+pub fn enchant(signer: Pubkey, collection: Pubkey, asset: Pubkey, enchantment: u64, mint: Pubkey) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+    let tokens = spl_associated_token_account::get_associated_token_address(&signer, &mint);
+
+    let enchant_v1_args = ForgeInstruction::EnchantV1(EnchantV1Args {
+        enchantment,
+        collection_authority_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(asset, false),
+            AccountMeta::new(tool, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new_readonly(collection_authority, false),
             AccountMeta::new_readonly(config, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(tokens, false),
             AccountMeta::new_readonly(MPL_CORE_ID, false),
             AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new(system_program::id(), false),
-            AccountMeta::new(INGOT_MINT_ADDEESS, false),
-            AccountMeta::new(ingot_tokens, false),
-            AccountMeta::new(WOOD_MINT_ADDRESS, false),
-            AccountMeta::new(wood_tokens, false),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
-        data: [mint_v1_args.try_to_vec().unwrap()].concat(),
+        data: [enchant_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, loadout, mpl_core_program, system_program
+pub fn equip(signer: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+    let (loadout, loadout_bump) = Pubkey::find_program_address(&[LOADOUT_SEED, signer.as_ref()], &crate::id());
+
+    let equip_v1_args = ForgeInstruction::EquipV1(EquipV1Args {
+        loadout_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(asset, false),
+            AccountMeta::new_readonly(tool, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new(loadout, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [equip_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, collection_info, collection_authority, loadout, mpl_core_program, system_program
+pub fn unequip(signer: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let loadout = Pubkey::find_program_address(&[LOADOUT_SEED, signer.as_ref()], &crate::id()).0;
+
+    let unequip_v1_args = ForgeInstruction::UnequipV1(UnequipV1Args {
+        collection_authority_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(asset, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new_readonly(collection_authority, false),
+            AccountMeta::new(loadout, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [unequip_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, rental, mpl_core_program, system_program
+pub fn list_tool(signer: Pubkey, collection: Pubkey, asset: Pubkey, price: u64, duration: u64) -> Instruction {
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+    let (rental, rental_bump) = Pubkey::find_program_address(&[RENTAL_SEED, asset.as_ref()], &crate::id());
+
+    let list_tool_v1_args = ForgeInstruction::ListToolV1(ListToolV1Args {
+        price,
+        duration,
+        rental_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(asset, false),
+            AccountMeta::new_readonly(tool, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new(rental, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [list_tool_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, owner, royalty_creator, rental, loadout, system_program
+pub fn rent_tool(signer: Pubkey, owner: Pubkey, asset: Pubkey) -> Instruction {
+    let rental = Pubkey::find_program_address(&[RENTAL_SEED, asset.as_ref()], &crate::id()).0;
+    let (loadout, loadout_bump) = Pubkey::find_program_address(&[LOADOUT_SEED, signer.as_ref()], &crate::id());
+
+    let rent_tool_v1_args = ForgeInstruction::RentToolV1(RentToolV1Args {
+        loadout_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(ROYALTY_CREATOR_ADDRESS, false),
+            AccountMeta::new(rental, false),
+            AccountMeta::new(loadout, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [rent_tool_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, owner, asset, collection_info, rental, loadout, mpl_core_program, system_program
+// The loadout is the renter's, and is only read if the tool is rented.
+pub fn return_tool(signer: Pubkey, owner: Pubkey, renter: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let (rental, rental_bump) = Pubkey::find_program_address(&[RENTAL_SEED, asset.as_ref()], &crate::id());
+    let loadout = Pubkey::find_program_address(&[LOADOUT_SEED, renter.as_ref()], &crate::id()).0;
+
+    let return_tool_v1_args = ForgeInstruction::ReturnToolV1(ReturnToolV1Args {
+        rental_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(asset, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new(rental, false),
+            AccountMeta::new(loadout, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [return_tool_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, owner, asset, tool, collection_info, collection_authority, config, loadout, treasury, mpl_core_program, system_program
+pub fn reap(signer: Pubkey, owner: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let (collection_authority, collection_authority_bump) = Pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::id());
+    let config = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+    let loadout = Pubkey::find_program_address(&[LOADOUT_SEED, owner.as_ref()], &crate::id()).0;
+
+    let reap_v1_args = ForgeInstruction::ReapV1(ReapV1Args {
+        collection_authority_bump,
+    });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(asset, false),
+            AccountMeta::new(tool, false),
+            AccountMeta::new(collection, false),
+            AccountMeta::new_readonly(collection_authority, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(loadout, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [reap_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, authority, loadout, tool
+// The signer is the COAL tool authority, signing through a cpi from the COAL program. The authority is the miner whose loadout holds the tool.
+pub fn use_tool(signer: Pubkey, authority: Pubkey, asset: Pubkey, amount: u64) -> Instruction {
+    let loadout = Pubkey::find_program_address(&[LOADOUT_SEED, authority.as_ref()], &crate::id()).0;
+    let tool = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+
+    let use_tool_v1_args = ForgeInstruction::UseToolV1(UseToolV1Args { amount });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new_readonly(loadout, false),
+            AccountMeta::new(tool, false),
+        ],
+        data: [use_tool_v1_args.try_to_vec().unwrap()].concat(),
+    }
+}
+
+// signer, asset, tool, collection_info, system_program
+pub fn init_tool(signer: Pubkey, collection: Pubkey, asset: Pubkey) -> Instruction {
+    let (tool, tool_bump) = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id());
+
+    let init_tool_v1_args = ForgeInstruction::InitToolV1(InitToolV1Args { tool_bump });
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(asset, false),
+            AccountMeta::new(tool, false),
+            AccountMeta::new_readonly(collection, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [init_tool_v1_args.try_to_vec().unwrap()].concat(),
     }
 }
@@ -4,6 +4,9 @@ pub mod event;
 pub mod instruction;
 pub mod state;
 pub mod loaders;
+pub mod merkle;
+pub mod pricing;
+pub mod rarity;
 
 pub(crate) use forge_utils as utils;
 
@@ -5,7 +5,7 @@ use solana_program::{
 use spl_token::state::Mint;
 
 use crate::{
-    consts::*, state::{Config, Treasury}, utils::Discriminator
+    consts::*, error::ForgeError, state::{decode_version, Commitment, Config, Loadout, Receipt, Recipe, Rental, Tool, Treasury}, utils::{AccountDeserialize, Discriminator}
 };
 
 /// Errors if:
@@ -193,11 +193,25 @@ pub fn load_program<'a, 'info>(
     Ok(())
 }
 
+/// Errors if:
+/// - Address does not match the expected sysvar address.
+pub fn load_sysvar<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    key: Pubkey,
+) -> Result<(), ProgramError> {
+    if info.key.ne(&key) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
 /// Errors if:
 /// - Owner is not Ore program.
 /// - Address does not match the expected address.
 /// - Data is empty.
 /// - Data cannot deserialize into a coal config account.
+/// - Config layout version is not the current version.
 /// - Expected to be writable, but is not.
 pub fn load_config<'a, 'info>(
     info: &'a AccountInfo<'info>,
@@ -222,6 +236,130 @@ pub fn load_config<'a, 'info>(
         return Err(solana_program::program_error::ProgramError::InvalidAccountData);
     }
 
+    if !Config::try_from_bytes(&info.data.borrow())?.is_current_version() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected recipe address.
+/// - Data is empty.
+/// - Data cannot deserialize into a recipe account.
+/// - Recipe layout version is not the current version.
+/// - Expected to be writable, but is not.
+pub fn load_recipe<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+    id: u64,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[RECIPE_SEED, collection.as_ref(), &id.to_le_bytes()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let recipe_data = info.data.borrow();
+    let recipe = Recipe::try_from_bytes(&recipe_data)?;
+    if !recipe.is_current_version() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if recipe.collection.ne(&collection) || recipe.id.ne(&id) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected receipt address.
+/// - Data is empty.
+/// - Data cannot deserialize into a receipt account.
+/// - Expected to be writable, but is not.
+pub fn load_receipt<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+    authority: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[RECEIPT_SEED, collection.as_ref(), authority.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Receipt::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected commitment address.
+/// - Data is empty.
+/// - Data cannot deserialize into a commitment account.
+/// - Commitment is not at the current layout version.
+/// - Expected to be writable, but is not.
+pub fn load_commitment<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+    authority: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[COMMITMENT_SEED, collection.as_ref(), authority.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Commitment::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !Commitment::try_from_bytes(&info.data.borrow())?.is_current_version() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     if is_writable && !info.is_writable {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -260,4 +398,180 @@ pub fn load_treasury<'a, 'info>(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected tool address.
+/// - Data is empty.
+/// - Data cannot deserialize into a tool account.
+/// - Expected to be writable, but is not.
+pub fn load_tool<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    asset: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[TOOL_SEED, asset.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Tool::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected loadout address.
+/// - Data is empty.
+/// - Data cannot deserialize into a loadout account.
+/// - Expected to be writable, but is not.
+pub fn load_loadout<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    authority: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[LOADOUT_SEED, authority.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Loadout::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected rental address.
+/// - Data is empty.
+/// - Data cannot deserialize into a rental account.
+/// - Expected to be writable, but is not.
+pub fn load_rental<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    asset: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[RENTAL_SEED, asset.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Rental::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Forge program.
+/// - Address does not match the expected config address.
+/// - Data is empty.
+/// - Data is not a config account.
+/// - Config is already at the current layout version.
+/// - Expected to be writable, but is not.
+pub fn load_outdated_config<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let pda = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(Config::discriminator() as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if decode_version(&info.data.borrow())?.ge(&CONFIG_VERSION) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Returns true if the config of the collection was closed, retiring the collection.
+///
+/// Errors if:
+/// - Address does not match the expected config address.
+pub fn is_config_retired<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+) -> Result<bool, ProgramError> {
+    let pda = Pubkey::find_program_address(&[CONFIG_SEED, collection.as_ref()], &crate::id()).0;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(info.data_is_empty() && info.owner.eq(&system_program::ID))
+}
+
+/// Errors if:
+/// - Collection is retired.
+/// - Config cannot be loaded.
+pub fn load_active_config<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    collection: Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if is_config_retired(info, collection)? {
+        return Err(ForgeError::CollectionRetired.into());
+    }
+
+    load_config(info, collection, is_writable)
+}
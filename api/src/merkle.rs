@@ -0,0 +1,143 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// Domain separators so a leaf can never be passed off as an inner node.
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Returns the allowlist leaf of a wallet.
+pub fn leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref()]).to_bytes()
+}
+
+/// Returns the parent of two nodes. Children are sorted, so proofs carry no path directions.
+fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a.le(b) {
+        hashv(&[NODE_PREFIX, a, b]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, b, a]).to_bytes()
+    }
+}
+
+/// Returns true if the proof links the wallet's leaf to the root.
+pub fn verify(root: &[u8; 32], proof: &[[u8; 32]], wallet: &Pubkey) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf(wallet), |node, sibling| parent(&node, sibling));
+    computed.eq(root)
+}
+
+/// An allowlist merkle tree, built off-chain to derive the root and the wallet proofs.
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree over the given wallets. Nodes without a sibling are promoted unchanged.
+    pub fn new(wallets: &[Pubkey]) -> Self {
+        let mut layers = vec![wallets.iter().map(leaf).collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => parent(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    /// Returns the root of the tree, or zeroes if the tree is empty.
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the proof of a wallet, or None if the wallet is not in the tree.
+    pub fn proof(&self, wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let mut index = self.layers[0].iter().position(|node| node.eq(&leaf(wallet)))?;
+        let mut proof = vec![];
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallets(len: usize) -> Vec<Pubkey> {
+        (0..len).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn assert_round_trip(len: usize) {
+        let wallets = wallets(len);
+        let tree = MerkleTree::new(&wallets);
+        let root = tree.root();
+        for wallet in wallets.iter() {
+            let proof = tree.proof(wallet).unwrap();
+            assert!(verify(&root, &proof, wallet));
+        }
+    }
+
+    #[test]
+    fn test_round_trip_one_wallet() {
+        assert_round_trip(1);
+    }
+
+    #[test]
+    fn test_round_trip_two_wallets() {
+        assert_round_trip(2);
+    }
+
+    #[test]
+    fn test_round_trip_three_wallets() {
+        assert_round_trip(3);
+    }
+
+    #[test]
+    fn test_round_trip_five_wallets() {
+        assert_round_trip(5);
+    }
+
+    #[test]
+    fn test_rejects_non_member() {
+        let wallets = wallets(5);
+        let tree = MerkleTree::new(&wallets);
+        let outsider = Pubkey::new_unique();
+        assert!(tree.proof(&outsider).is_none());
+        for wallet in wallets.iter() {
+            let proof = tree.proof(wallet).unwrap();
+            assert!(!verify(&tree.root(), &proof, &outsider));
+        }
+    }
+
+    #[test]
+    fn test_rejects_tampered_proof() {
+        let wallets = wallets(5);
+        let tree = MerkleTree::new(&wallets);
+        for wallet in wallets.iter() {
+            let proof = tree.proof(wallet).unwrap();
+            for i in 0..proof.len() {
+                let mut tampered = proof.clone();
+                tampered[i][0] ^= 1;
+                assert!(!verify(&tree.root(), &tampered, wallet));
+            }
+            let mut extended = proof.clone();
+            extended.push(leaf(wallet));
+            assert!(!verify(&tree.root(), &extended, wallet));
+        }
+    }
+}
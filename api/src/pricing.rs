@@ -0,0 +1,233 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{
+    consts::MAX_BASIS_POINTS,
+    state::{Config, Ingredient},
+};
+
+/// Fixed-point scale of price factors.
+const FACTOR_SCALE: u128 = 1_000_000_000_000;
+
+/// Largest price factor, past which amounts saturate anyway.
+const MAX_FACTOR: u128 = u64::MAX as u128 * FACTOR_SCALE;
+
+/// How ingredient amounts grow with the number of tools minted.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, BorshSerialize, BorshDeserialize)]
+#[borsh(use_discriminant = true)]
+pub enum Curve {
+    /// Amounts never change.
+    None = 0,
+    /// Amounts grow by `curve_rate` basis points of the base amount per tool minted.
+    Linear = 1,
+    /// Amounts compound by `curve_rate` basis points per tool minted.
+    Exponential = 2,
+}
+
+/// Returns the ingredients of a recipe with the amounts due for the next craft at the given
+/// unix timestamp.
+pub fn quote(config: &Config, ingredients: &[Ingredient], now: i64) -> Vec<Ingredient> {
+    let factor = mul_factor(price_factor(config), auction_factor(config, now));
+    ingredients
+        .iter()
+        .map(|ingredient| Ingredient {
+            amount: scale(ingredient.amount, factor),
+            ..*ingredient
+        })
+        .collect()
+}
+
+/// Returns the fixed-point factor applied to base amounts for the next craft.
+fn price_factor(config: &Config) -> u128 {
+    let rate = config.curve_rate as u128;
+    let minted = config.minted;
+    match Curve::try_from(config.curve as u8).unwrap_or(Curve::None) {
+        Curve::None => FACTOR_SCALE,
+        Curve::Linear => rate
+            .saturating_mul(minted as u128)
+            .saturating_mul(FACTOR_SCALE)
+            .checked_div(MAX_BASIS_POINTS as u128)
+            .unwrap_or(0)
+            .saturating_add(FACTOR_SCALE)
+            .min(MAX_FACTOR),
+        Curve::Exponential => {
+            let base = FACTOR_SCALE + rate.saturating_mul(FACTOR_SCALE) / MAX_BASIS_POINTS as u128;
+            pow_factor(base.min(MAX_FACTOR), minted)
+        }
+    }
+}
+
+/// Returns the fixed-point factor of the launch auction at the given unix timestamp. Decays
+/// linearly from the start to the floor over the auction duration.
+fn auction_factor(config: &Config, now: i64) -> u128 {
+    if config.auction_duration.le(&0) {
+        return FACTOR_SCALE;
+    }
+
+    let start = config.auction_start_basis_points as u128;
+    let floor = config.auction_floor_basis_points.min(config.auction_start_basis_points) as u128;
+    let duration = config.auction_duration as u128;
+    let elapsed = now.saturating_sub(config.start_at).max(0) as u128;
+    let decayed = (start - floor).saturating_mul(elapsed.min(duration)) / duration;
+    (start - decayed)
+        .saturating_mul(FACTOR_SCALE)
+        .checked_div(MAX_BASIS_POINTS as u128)
+        .unwrap_or(0)
+        .min(MAX_FACTOR)
+}
+
+/// Raises a fixed-point factor to an integer power, saturating at the max factor.
+fn pow_factor(mut base: u128, mut exp: u64) -> u128 {
+    let mut result = FACTOR_SCALE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_factor(result, base);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul_factor(base, base);
+        }
+    }
+    result
+}
+
+/// Multiplies two fixed-point factors, saturating at the max factor.
+fn mul_factor(a: u128, b: u128) -> u128 {
+    a.checked_mul(b)
+        .map(|product| product / FACTOR_SCALE)
+        .unwrap_or(MAX_FACTOR)
+        .min(MAX_FACTOR)
+}
+
+/// Applies a fixed-point factor to an amount, saturating at the max amount.
+fn scale(amount: u64, factor: u128) -> u64 {
+    (amount as u128)
+        .checked_mul(factor)
+        .map(|scaled| scaled / FACTOR_SCALE)
+        .unwrap_or(u64::MAX as u128)
+        .min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn config(curve: Curve, curve_rate: u64, minted: u64) -> Config {
+        let mut config = Config::zeroed();
+        config.curve = u8::from(curve) as u64;
+        config.curve_rate = curve_rate;
+        config.minted = minted;
+        config
+    }
+
+    fn ingredient(amount: u64) -> Ingredient {
+        Ingredient {
+            mint: Pubkey::new_unique(),
+            amount,
+            burn_basis_points: MAX_BASIS_POINTS,
+        }
+    }
+
+    #[test]
+    fn test_price_factor_first_mint() {
+        assert_eq!(price_factor(&config(Curve::None, 100, 0)), FACTOR_SCALE);
+        assert_eq!(price_factor(&config(Curve::Linear, 100, 0)), FACTOR_SCALE);
+        assert_eq!(price_factor(&config(Curve::Exponential, 100, 0)), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_price_factor_grows_with_minted() {
+        // 1% of the base amount per tool.
+        assert_eq!(price_factor(&config(Curve::Linear, 100, 10)), FACTOR_SCALE * 11 / 10);
+        // Doubles per tool.
+        assert_eq!(price_factor(&config(Curve::Exponential, MAX_BASIS_POINTS, 3)), FACTOR_SCALE * 8);
+        // Flat curves ignore minted.
+        assert_eq!(price_factor(&config(Curve::None, MAX_BASIS_POINTS, u64::MAX)), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_price_factor_saturates_with_large_minted() {
+        assert_eq!(price_factor(&config(Curve::Linear, u64::MAX, u64::MAX)), MAX_FACTOR);
+        assert_eq!(price_factor(&config(Curve::Exponential, MAX_BASIS_POINTS, u64::MAX)), MAX_FACTOR);
+        assert_eq!(price_factor(&config(Curve::Exponential, u64::MAX, 2)), MAX_FACTOR);
+    }
+
+    #[test]
+    fn test_pow_factor() {
+        assert_eq!(pow_factor(FACTOR_SCALE * 2, 0), FACTOR_SCALE);
+        assert_eq!(pow_factor(FACTOR_SCALE * 2, 1), FACTOR_SCALE * 2);
+        assert_eq!(pow_factor(FACTOR_SCALE * 2, 10), FACTOR_SCALE * 1024);
+        assert_eq!(pow_factor(FACTOR_SCALE, u64::MAX), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_pow_factor_saturates() {
+        assert_eq!(pow_factor(FACTOR_SCALE * 2, 200), MAX_FACTOR);
+        assert_eq!(pow_factor(MAX_FACTOR, 2), MAX_FACTOR);
+    }
+
+    #[test]
+    fn test_quote() {
+        let ingredients = [ingredient(1_000), ingredient(0)];
+        let quoted = quote(&config(Curve::Linear, 100, 10), &ingredients, 0);
+        assert_eq!(quoted[0].amount, 1_100);
+        assert_eq!(quoted[0].mint, ingredients[0].mint);
+        assert_eq!(quoted[0].burn_basis_points, ingredients[0].burn_basis_points);
+        assert_eq!(quoted[1].amount, 0);
+
+        let quoted = quote(&config(Curve::None, 0, u64::MAX), &ingredients, 0);
+        assert_eq!(quoted[0].amount, 1_000);
+    }
+
+    fn auction(now: i64) -> u128 {
+        let mut config = Config::zeroed();
+        config.start_at = 1_000;
+        config.auction_start_basis_points = 2 * MAX_BASIS_POINTS;
+        config.auction_floor_basis_points = MAX_BASIS_POINTS;
+        config.auction_duration = 100;
+        auction_factor(&config, now)
+    }
+
+    #[test]
+    fn test_auction_factor_without_auction() {
+        assert_eq!(auction_factor(&Config::zeroed(), 1_000), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_auction_factor_before_start() {
+        assert_eq!(auction(0), FACTOR_SCALE * 2);
+        assert_eq!(auction(999), FACTOR_SCALE * 2);
+    }
+
+    #[test]
+    fn test_auction_factor_at_start() {
+        assert_eq!(auction(1_000), FACTOR_SCALE * 2);
+    }
+
+    #[test]
+    fn test_auction_factor_mid_decay() {
+        assert_eq!(auction(1_025), FACTOR_SCALE * 7 / 4);
+        assert_eq!(auction(1_050), FACTOR_SCALE * 3 / 2);
+    }
+
+    #[test]
+    fn test_auction_factor_at_floor() {
+        assert_eq!(auction(1_100), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_auction_factor_after_end() {
+        assert_eq!(auction(1_101), FACTOR_SCALE);
+        assert_eq!(auction(i64::MAX), FACTOR_SCALE);
+    }
+
+    #[test]
+    fn test_quote_saturates() {
+        let quoted = quote(&config(Curve::Exponential, MAX_BASIS_POINTS, u64::MAX), &[ingredient(1)], 0);
+        assert_eq!(quoted[0].amount, u64::MAX);
+    }
+}
@@ -0,0 +1,219 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+use crate::state::Config;
+
+/// The attributes rolled for a crafted tool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Roll {
+    pub rarity: String,
+    pub multiplier: u64,
+    pub durability: u64,
+}
+
+/// Returns the hash of the first slot at or after the given slot from the data of the SlotHashes
+/// sysvar, or None if the slot is not in its history yet or anymore.
+pub fn slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(data.get(..8)?.try_into().ok()?) as usize;
+    let mut found = None;
+    // Entries are newest first, so the last match is the closest slot.
+    for entry in data.get(8..)?.chunks_exact(40).take(len) {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().ok()?);
+        if entry_slot.lt(&slot) {
+            return found;
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..40]);
+        found = Some(hash);
+        if entry_slot.eq(&slot) {
+            return found;
+        }
+    }
+    // Every entry is newer than the slot, so it may have left the history.
+    None
+}
+
+/// Returns the seed a craft is rolled from, unique to the slot hash, crafter and commitment.
+pub fn seed(slot_hash: &[u8; 32], signer: &Pubkey, commitment: &Pubkey) -> [u8; 32] {
+    hashv(&[slot_hash, signer.as_ref(), commitment.as_ref()]).to_bytes()
+}
+
+/// Returns the fixed attributes of the config, which tools get without a roll. Crafts that
+/// complete in a single transaction use these, as a roll made there could be predicted and
+/// ground; collections with rarity tiers are only crafted through commit and reveal.
+pub fn base(config: &Config) -> Roll {
+    Roll {
+        rarity: config.rarity(),
+        multiplier: config.multiplier,
+        durability: config.durability,
+    }
+}
+
+/// Rolls the attributes of a tool. Collections without rarity tiers always roll the
+/// fixed attributes of their config.
+pub fn roll(config: &Config, seed: &[u8; 32]) -> Roll {
+    let tiers = config.rarity_tiers();
+    let total_weight = tiers
+        .iter()
+        .fold(0u64, |total, tier| total.saturating_add(tier.weight));
+    if total_weight == 0 {
+        return base(config);
+    }
+
+    // Pick a tier by weight.
+    let mut pick = read_u64(seed, 0) % total_weight;
+    let tier = tiers
+        .iter()
+        .find(|tier| {
+            if pick < tier.weight {
+                return true;
+            }
+            pick -= tier.weight;
+            false
+        })
+        .unwrap_or(&tiers[tiers.len() - 1]);
+
+    Roll {
+        rarity: tier.name(),
+        multiplier: pick_in_range(tier.min_multiplier, tier.max_multiplier, read_u64(seed, 8)),
+        durability: pick_in_range(tier.min_durability, tier.max_durability, read_u64(seed, 16)),
+    }
+}
+
+/// Reads a little-endian u64 from the seed at the given offset.
+fn read_u64(seed: &[u8; 32], offset: usize) -> u64 {
+    u64::from_le_bytes(seed[offset..offset + 8].try_into().unwrap())
+}
+
+/// Maps a random value into the inclusive range.
+fn pick_in_range(min: u64, max: u64, random: u64) -> u64 {
+    match max.saturating_sub(min).checked_add(1) {
+        Some(span) => min + random % span,
+        None => random,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::{state::RarityTier, utils::string_to_bytes};
+
+    use super::*;
+
+    fn config(tiers: &[RarityTier]) -> Config {
+        let mut config = Config::zeroed();
+        config.multiplier = 2;
+        config.durability = 100;
+        config.rarity = string_to_bytes("Common").unwrap();
+        config.set_rarity_tiers(tiers).unwrap();
+        config
+    }
+
+    fn tier(name: &str, weight: u64, multiplier: (u64, u64), durability: (u64, u64)) -> RarityTier {
+        RarityTier {
+            name: string_to_bytes(name).unwrap(),
+            weight,
+            min_multiplier: multiplier.0,
+            max_multiplier: multiplier.1,
+            min_durability: durability.0,
+            max_durability: durability.1,
+        }
+    }
+
+    fn roll_seed(pick: u64, multiplier: u64, durability: u64) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        seed[..8].copy_from_slice(&pick.to_le_bytes());
+        seed[8..16].copy_from_slice(&multiplier.to_le_bytes());
+        seed[16..24].copy_from_slice(&durability.to_le_bytes());
+        seed
+    }
+
+    #[test]
+    fn test_roll_without_tiers() {
+        let config = config(&[]);
+        assert_eq!(roll(&config, &roll_seed(7, 7, 7)), base(&config));
+    }
+
+    #[test]
+    fn test_roll_picks_tier_by_weight() {
+        let config = config(&[tier("Rare", 3, (1, 1), (1, 1)), tier("Epic", 1, (2, 2), (2, 2))]);
+        assert_eq!(roll(&config, &roll_seed(0, 0, 0)).rarity, "Rare");
+        assert_eq!(roll(&config, &roll_seed(2, 0, 0)).rarity, "Rare");
+        assert_eq!(roll(&config, &roll_seed(3, 0, 0)).rarity, "Epic");
+        assert_eq!(roll(&config, &roll_seed(4, 0, 0)).rarity, "Rare");
+    }
+
+    #[test]
+    fn test_roll_within_ranges() {
+        let config = config(&[tier("Rare", 1, (2, 4), (50, 60))]);
+        assert_eq!(
+            roll(&config, &roll_seed(0, 0, 0)),
+            Roll {
+                rarity: "Rare".to_string(),
+                multiplier: 2,
+                durability: 50,
+            }
+        );
+        assert_eq!(
+            roll(&config, &roll_seed(0, 2, 10)),
+            Roll {
+                rarity: "Rare".to_string(),
+                multiplier: 4,
+                durability: 60,
+            }
+        );
+        assert_eq!(
+            roll(&config, &roll_seed(0, 3, 11)),
+            Roll {
+                rarity: "Rare".to_string(),
+                multiplier: 2,
+                durability: 50,
+            }
+        );
+    }
+
+    #[test]
+    fn test_roll_full_range() {
+        let config = config(&[tier("Rare", 1, (0, u64::MAX), (0, u64::MAX))]);
+        let roll = roll(&config, &roll_seed(0, u64::MAX, 42));
+        assert_eq!(roll.multiplier, u64::MAX);
+        assert_eq!(roll.durability, 42);
+    }
+
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots.iter() {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn test_slot_hash_exact_slot() {
+        let data = slot_hashes(&[10, 8, 7]);
+        assert_eq!(slot_hash(&data, 10), Some([10; 32]));
+        assert_eq!(slot_hash(&data, 8), Some([8; 32]));
+        assert_eq!(slot_hash(&data, 7), Some([7; 32]));
+    }
+
+    #[test]
+    fn test_slot_hash_skipped_slot() {
+        let data = slot_hashes(&[10, 8, 7]);
+        assert_eq!(slot_hash(&data, 9), Some([10; 32]));
+    }
+
+    #[test]
+    fn test_slot_hash_not_yet_in_history() {
+        let data = slot_hashes(&[10, 8, 7]);
+        assert_eq!(slot_hash(&data, 11), None);
+    }
+
+    #[test]
+    fn test_slot_hash_left_history() {
+        let data = slot_hashes(&[10, 8, 7]);
+        assert_eq!(slot_hash(&data, 6), None);
+        assert_eq!(slot_hash(&slot_hashes(&[]), 6), None);
+        assert_eq!(slot_hash(&[], 6), None);
+    }
+}
@@ -0,0 +1,65 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    consts::{COMMITMENT_VERSION, MAX_INGREDIENTS},
+    utils::{impl_account_from_bytes, impl_to_bytes, Discriminator},
+};
+
+use super::{AccountDiscriminator, Escrow};
+
+/// Commitment records a craft whose ingredients are consumed, awaiting the reveal of its rolled
+/// attributes from the hash of a future slot.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Commitment {
+    /// The layout version of the account.
+    pub version: u64,
+
+    /// The wallet that committed the craft.
+    pub authority: Pubkey,
+
+    /// The collection the tool is crafted in.
+    pub collection: Pubkey,
+
+    /// The slot whose hash the attributes are rolled from.
+    pub target_slot: u64,
+
+    /// The last slot the craft can be revealed in. Unrevealed crafts are forfeited afterwards.
+    pub deadline_slot: u64,
+
+    /// The id of the recipe the craft consumed.
+    pub recipe: u64,
+
+    /// The number of ingredients the craft escrowed into the treasury.
+    pub escrow_len: u64,
+
+    /// The ingredients the craft escrowed into the treasury.
+    pub escrow: [Escrow; MAX_INGREDIENTS],
+}
+
+impl Commitment {
+    /// Returns true if the commitment can no longer be revealed at the given slot.
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot.gt(&self.deadline_slot)
+    }
+
+    /// Returns the ingredients the craft escrowed into the treasury.
+    pub fn escrow(&self) -> &[Escrow] {
+        &self.escrow[..(self.escrow_len as usize).min(MAX_INGREDIENTS)]
+    }
+
+    /// Returns true if the commitment was written with the current layout version.
+    pub fn is_current_version(&self) -> bool {
+        self.version.eq(&COMMITMENT_VERSION)
+    }
+}
+
+impl Discriminator for Commitment {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Commitment.into()
+    }
+}
+
+impl_to_bytes!(Commitment);
+impl_account_from_bytes!(Commitment);
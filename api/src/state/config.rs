@@ -1,18 +1,324 @@
-
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::utils::{impl_account_from_bytes, impl_to_bytes, Discriminator};
+use crate::{
+    consts::{CONFIG_VERSION, MAX_BASIS_POINTS, MAX_ENCHANTMENTS, MAX_NAME_LEN, MAX_RARITY_TIERS, MAX_URI_LEN, LEGACY_CONFIG_SIZE, RESERVED_ATTRIBUTES},
+    error::ForgeError,
+    utils::{bytes_to_string, impl_account_from_bytes, impl_to_bytes, AccountDeserialize, Discriminator},
+};
 
-use super::AccountDiscriminator;
+use super::{AccountDiscriminator, Ingredient};
 
+/// Config holds the tool metadata of a collection. The ingredients needed to craft
+/// a tool are stored in the collection's recipe accounts.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct Config {
+    /// The layout version of the account.
+    pub version: u64,
+
+    /// The number of recipes created for the collection. Also the id of the next recipe.
+    pub recipes: u64,
+
+    /// The multiplier attribute of minted tools.
+    pub multiplier: u64,
+
+    /// The durability attribute of minted tools.
+    pub durability: u64,
+
+    /// The name of minted tools, zero-padded.
+    pub name: [u8; MAX_NAME_LEN],
+
+    /// The metadata uri of minted tools, zero-padded.
+    pub uri: [u8; MAX_URI_LEN],
+
+    /// The rarity attribute of minted tools, zero-padded.
+    pub rarity: [u8; MAX_NAME_LEN],
+
+    /// The resource attribute of minted tools, zero-padded.
+    pub resource: [u8; MAX_NAME_LEN],
+
+    /// The maximum number of tools that can be minted. Zero if unlimited.
+    pub max_supply: u64,
+
+    /// The number of tools minted so far.
+    pub minted: u64,
+
+    /// The unix timestamp at which crafting opens. Zero if crafting is open from creation.
+    pub start_at: i64,
+
+    /// The unix timestamp at which crafting closes. Zero if crafting never closes.
+    pub end_at: i64,
+
+    /// The maximum number of tools a single wallet can mint. Zero if unlimited.
+    pub wallet_limit: u64,
+
+    /// The merkle root of wallets allowed to craft. Zeroes if crafting is open to everyone.
+    pub merkle_root: [u8; 32],
+
+    /// The lamports paid into the treasury per craft, on top of the ingredients.
+    pub fee: u64,
+
+    /// The pricing curve scaling ingredient amounts with the number of tools minted.
+    pub curve: u64,
+
+    /// The growth rate of the pricing curve, in basis points per tool minted.
+    pub curve_rate: u64,
+
+    /// The launch auction price at `start_at`, in basis points of the recipe amounts.
+    pub auction_start_basis_points: u64,
+
+    /// The launch auction price after it fully decays, in basis points of the recipe amounts.
+    pub auction_floor_basis_points: u64,
+
+    /// The seconds the launch auction takes to decay to its floor. Zero if there is no auction.
+    pub auction_duration: i64,
+
+    /// The number of rarity tiers in use. Zero if tools always get the fixed attributes above.
+    pub rarity_tiers_len: u64,
+
+    /// The weighted rarity tiers rolled at mint.
+    pub rarity_tiers: [RarityTier; MAX_RARITY_TIERS],
+
+    /// The id of the recipe consumed to repair a tool.
+    pub repair_recipe: u64,
+
+    /// The durability restored per repair, capped at the tool's max durability. Zero if tools
+    /// cannot be repaired.
+    pub repair_durability: u64,
+
+    /// The share of a tool's recipe refunded when it is dismantled at full durability, in basis
+    /// points. Scaled down by the durability the tool has left. Zero if tools cannot be dismantled.
+    pub salvage_basis_points: u64,
+
+    /// The collection tools are reforged into.
+    pub upgrade_collection: Pubkey,
+
+    /// The number of tools consumed per reforge. Zero if tools cannot be reforged.
+    pub upgrade_inputs: u64,
+
+    /// The id of the recipe consumed per reforge, on top of the tools.
+    pub upgrade_recipe: u64,
+
+    /// The number of times a single tool can be enchanted. Zero if tools cannot be enchanted.
+    pub enchant_slots: u64,
+
+    /// The number of enchantments in use.
+    pub enchantments_len: u64,
+
+    /// The enchantments tools of the collection can receive.
+    pub enchantments: [Enchantment; MAX_ENCHANTMENTS],
+
+    /// Whether minted tools are permanently frozen to their owner. Zero if tools are tradable.
+    pub soulbound: u64,
+
+    /// The lamports paid from the treasury for reaping a worn out tool. Zero if reaping is unpaid.
+    pub reap_reward: u64,
+
+    /// The number of committed crafts not yet revealed or forfeited.
+    pub commitments: u64,
+}
+
+/// LegacyConfig is the layout of config accounts created before configs were versioned. Each
+/// ingredient was burned in full per craft.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct LegacyConfig {
     pub amounts: [u64; 3],
     pub ingredients: [Pubkey; 3],
 }
 
+impl LegacyConfig {
+    /// Returns the ingredients of the legacy recipe, skipping unused entries.
+    pub fn ingredients(&self) -> Vec<Ingredient> {
+        self.ingredients
+            .iter()
+            .zip(self.amounts.iter())
+            .filter(|(mint, amount)| (**mint).ne(&Pubkey::default()) && (**amount).gt(&0))
+            .map(|(mint, amount)| Ingredient {
+                mint: *mint,
+                amount: *amount,
+                burn_basis_points: MAX_BASIS_POINTS,
+            })
+            .collect()
+    }
+}
+
+impl Discriminator for LegacyConfig {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Config.into()
+    }
+}
+
+impl_account_from_bytes!(LegacyConfig);
+
+/// A weighted rarity tier with the attribute ranges its tools are rolled in.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, BorshSerialize, BorshDeserialize)]
+pub struct RarityTier {
+    /// The rarity attribute of tools in this tier, zero-padded.
+    pub name: [u8; MAX_NAME_LEN],
+
+    /// The relative chance of rolling this tier.
+    pub weight: u64,
+
+    /// The inclusive range of the multiplier attribute.
+    pub min_multiplier: u64,
+    pub max_multiplier: u64,
+
+    /// The inclusive range of the durability attribute.
+    pub min_durability: u64,
+    pub max_durability: u64,
+}
+
+impl RarityTier {
+    /// Returns the rarity attribute of tools in this tier.
+    pub fn name(&self) -> String {
+        bytes_to_string(&self.name)
+    }
+
+    /// Returns true if the tier has a weight and well-formed ranges.
+    pub fn is_valid(&self) -> bool {
+        self.weight.gt(&0)
+            && self.min_multiplier.le(&self.max_multiplier)
+            && self.min_durability.le(&self.max_durability)
+    }
+}
+
+/// An enchantment raising a bonus attribute of a tool in exchange for burning tokens.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, BorshSerialize, BorshDeserialize)]
+pub struct Enchantment {
+    /// The attribute raised, zero-padded. Raising "multiplier" also raises the mining multiplier.
+    pub name: [u8; MAX_NAME_LEN],
+
+    /// The mint of the tokens burned per enchant.
+    pub mint: Pubkey,
+
+    /// The amount of tokens burned per enchant.
+    pub cost: u64,
+
+    /// The amount the attribute is raised by per enchant.
+    pub bonus: u64,
+
+    /// The value past which the attribute cannot be raised.
+    pub max_value: u64,
+}
+
+impl Enchantment {
+    /// Returns the attribute raised by this enchantment.
+    pub fn name(&self) -> String {
+        bytes_to_string(&self.name)
+    }
+
+    /// Returns true if the enchantment raises an unreserved attribute by a positive bonus.
+    pub fn is_valid(&self) -> bool {
+        let name = self.name();
+        !name.is_empty() && !RESERVED_ATTRIBUTES.contains(&name.as_str()) && self.bonus.gt(&0)
+    }
+}
+
+impl Config {
+    /// Returns the name of minted tools.
+    pub fn name(&self) -> String {
+        bytes_to_string(&self.name)
+    }
+
+    /// Returns the metadata uri of minted tools.
+    pub fn uri(&self) -> String {
+        bytes_to_string(&self.uri)
+    }
+
+    /// Returns the rarity attribute of minted tools.
+    pub fn rarity(&self) -> String {
+        bytes_to_string(&self.rarity)
+    }
+
+    /// Returns the resource attribute of minted tools.
+    pub fn resource(&self) -> String {
+        bytes_to_string(&self.resource)
+    }
+
+    /// Returns the number of tools that can still be minted, or None if the supply is unlimited.
+    pub fn remaining_supply(&self) -> Option<u64> {
+        if self.max_supply == 0 {
+            return None;
+        }
+        Some(self.max_supply.saturating_sub(self.minted))
+    }
+
+    /// Returns true if crafting has not opened yet at the given unix timestamp.
+    pub fn is_before_start(&self, now: i64) -> bool {
+        self.start_at.gt(&0) && now.lt(&self.start_at)
+    }
+
+    /// Returns true if crafting has closed at the given unix timestamp.
+    pub fn is_after_end(&self, now: i64) -> bool {
+        self.end_at.gt(&0) && now.ge(&self.end_at)
+    }
+
+    /// Returns the rarity tiers in use.
+    pub fn rarity_tiers(&self) -> &[RarityTier] {
+        &self.rarity_tiers[..(self.rarity_tiers_len as usize).min(MAX_RARITY_TIERS)]
+    }
+
+    /// Replaces the rarity tiers. Errors if there are too many tiers or one is invalid.
+    pub fn set_rarity_tiers(&mut self, tiers: &[RarityTier]) -> Result<(), ForgeError> {
+        if tiers.len() > MAX_RARITY_TIERS || !tiers.iter().all(RarityTier::is_valid) {
+            return Err(ForgeError::InvalidRarityTiers);
+        }
+
+        self.rarity_tiers = [RarityTier::zeroed(); MAX_RARITY_TIERS];
+        self.rarity_tiers[..tiers.len()].copy_from_slice(tiers);
+        self.rarity_tiers_len = tiers.len() as u64;
+        Ok(())
+    }
+
+    /// Returns the enchantments in use.
+    pub fn enchantments(&self) -> &[Enchantment] {
+        &self.enchantments[..(self.enchantments_len as usize).min(MAX_ENCHANTMENTS)]
+    }
+
+    /// Replaces the enchantments. Errors if there are too many enchantments or one is invalid.
+    pub fn set_enchantments(&mut self, enchantments: &[Enchantment]) -> Result<(), ForgeError> {
+        if enchantments.len() > MAX_ENCHANTMENTS || !enchantments.iter().all(Enchantment::is_valid) {
+            return Err(ForgeError::InvalidEnchantments);
+        }
+
+        self.enchantments = [Enchantment::zeroed(); MAX_ENCHANTMENTS];
+        self.enchantments[..enchantments.len()].copy_from_slice(enchantments);
+        self.enchantments_len = enchantments.len() as u64;
+        Ok(())
+    }
+
+    /// Returns true if crafting is gated by an allowlist.
+    pub fn has_allowlist(&self) -> bool {
+        self.merkle_root.ne(&[0; 32])
+    }
+
+    /// Returns true if tools of the collection can be repaired.
+    pub fn is_repairable(&self) -> bool {
+        self.repair_durability.gt(&0)
+    }
+
+    /// Returns true if tools of the collection can be reforged.
+    pub fn is_upgradeable(&self) -> bool {
+        self.upgrade_inputs.gt(&0)
+    }
+
+    /// Returns true if tools can be crafted with the given recipe.
+    pub fn is_craft_recipe(&self, id: u64) -> bool {
+        !(self.is_repairable() && self.repair_recipe.eq(&id))
+            && !(self.is_upgradeable() && self.upgrade_recipe.eq(&id))
+    }
+
+    /// Returns true if the config was written with the current layout version.
+    pub fn is_current_version(&self) -> bool {
+        self.version.eq(&CONFIG_VERSION)
+    }
+}
+
 impl Discriminator for Config {
     fn discriminator() -> u8 {
         AccountDiscriminator::Config.into()
@@ -21,3 +327,71 @@ impl Discriminator for Config {
 
 impl_to_bytes!(Config);
 impl_account_from_bytes!(Config);
+
+/// Decodes the layout version from the data of a config account. Legacy configs are version zero.
+pub fn decode_version(data: &[u8]) -> Result<u64, ProgramError> {
+    if data.len().eq(&LEGACY_CONFIG_SIZE) {
+        return Ok(0);
+    }
+    let bytes = data.get(8..16).ok_or(ProgramError::InvalidAccountData)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Decodes the number of tools minted from the data of a config account.
+pub fn decode_minted(data: &[u8]) -> Result<u64, ProgramError> {
+    Ok(Config::try_from_bytes(data)?.minted)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use super::*;
+
+    #[test]
+    fn test_legacy_ingredients_skip_unused_entries() {
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::default()];
+        let legacy = LegacyConfig {
+            amounts: [10, 0, 30],
+            ingredients: mints,
+        };
+        assert_eq!(
+            legacy.ingredients(),
+            vec![Ingredient {
+                mint: mints[0],
+                amount: 10,
+                burn_basis_points: MAX_BASIS_POINTS,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_legacy_ingredients_burn_in_full() {
+        let legacy = LegacyConfig {
+            amounts: [1, 2, 3],
+            ingredients: [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        let ingredients = legacy.ingredients();
+        assert_eq!(ingredients.len(), 3);
+        assert!(ingredients
+            .iter()
+            .all(|ingredient| ingredient.split() == (ingredient.amount, 0)));
+    }
+
+    #[test]
+    fn test_decode_version_legacy() {
+        assert_eq!(decode_version(&[0u8; LEGACY_CONFIG_SIZE]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_decode_version_current() {
+        let mut data = vec![0u8; 8 + size_of::<Config>()];
+        data[8..16].copy_from_slice(&CONFIG_VERSION.to_le_bytes());
+        assert_eq!(decode_version(&data).unwrap(), CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_decode_version_too_short() {
+        assert!(decode_version(&[0u8; 8]).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    consts::{MAX_LOADOUT_SLOTS, MAX_NAME_LEN},
+    utils::{impl_account_from_bytes, impl_to_bytes, Discriminator},
+};
+
+use super::AccountDiscriminator;
+
+/// Loadout records the tool a wallet has equipped for each resource. Equipped tools are frozen
+/// until they are unequipped.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Loadout {
+    /// The wallet the loadout belongs to.
+    pub authority: Pubkey,
+
+    /// The equipped tools. Empty slots are zeroed.
+    pub slots: [LoadoutSlot; MAX_LOADOUT_SLOTS],
+}
+
+/// A tool equipped for a resource.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct LoadoutSlot {
+    /// The resource attribute of the tool, zero-padded.
+    pub resource: [u8; MAX_NAME_LEN],
+
+    /// The tool asset.
+    pub asset: Pubkey,
+
+    /// The slot the rental of the tool expires at. Zero if the tool is not rented.
+    pub expires_slot: u64,
+}
+
+impl LoadoutSlot {
+    /// Returns true if no tool is equipped in the slot.
+    pub fn is_empty(&self) -> bool {
+        self.asset.eq(&Pubkey::default())
+    }
+
+    /// Returns true if the tool is rented and its rental has expired at the given slot.
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expires_slot.gt(&0) && slot.ge(&self.expires_slot)
+    }
+
+    /// Empties the slot.
+    pub fn clear(&mut self) {
+        *self = Self::zeroed();
+    }
+}
+
+impl Loadout {
+    /// Returns the tool equipped for the given resource at the given slot, if any. Tools whose
+    /// rental has expired are skipped.
+    pub fn tool(&self, resource: &[u8; MAX_NAME_LEN], slot: u64) -> Option<Pubkey> {
+        self.slots
+            .iter()
+            .find(|s| !s.is_empty() && !s.is_expired(slot) && s.resource.eq(resource))
+            .map(|s| s.asset)
+    }
+
+    /// Returns the slot the tool is equipped in, if any.
+    pub fn slot(&self, asset: &Pubkey) -> Option<&LoadoutSlot> {
+        self.slots.iter().find(|s| !s.is_empty() && s.asset.eq(asset))
+    }
+}
+
+impl Discriminator for Loadout {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Loadout.into()
+    }
+}
+
+impl_to_bytes!(Loadout);
+impl_account_from_bytes!(Loadout);
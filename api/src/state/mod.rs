@@ -1,7 +1,19 @@
+mod commitment;
 mod config;
+mod loadout;
+mod receipt;
+mod recipe;
+mod rental;
+mod tool;
 mod treasury;
 
+pub use commitment::*;
 pub use config::*;
+pub use loadout::*;
+pub use receipt::*;
+pub use recipe::*;
+pub use rental::*;
+pub use tool::*;
 pub use treasury::*;
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -11,4 +23,10 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 pub enum AccountDiscriminator {
     Config = 100,
     Treasury = 101,
+    Recipe = 102,
+    Receipt = 103,
+    Commitment = 104,
+    Tool = 105,
+    Loadout = 106,
+    Rental = 107,
 }
@@ -0,0 +1,30 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::utils::{impl_account_from_bytes, impl_to_bytes, Discriminator};
+
+use super::AccountDiscriminator;
+
+/// Receipt records how many tools of a collection a wallet has minted. It is only
+/// tracked for collections with a per-wallet limit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Receipt {
+    /// The wallet this receipt belongs to.
+    pub authority: Pubkey,
+
+    /// The collection this receipt counts mints of.
+    pub collection: Pubkey,
+
+    /// The number of tools minted by the wallet.
+    pub minted: u64,
+}
+
+impl Discriminator for Receipt {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Receipt.into()
+    }
+}
+
+impl_to_bytes!(Receipt);
+impl_account_from_bytes!(Receipt);
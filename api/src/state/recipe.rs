@@ -0,0 +1,138 @@
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    consts::{MAX_BASIS_POINTS, RECIPE_VERSION},
+    utils::{impl_account_from_prefix_bytes, impl_to_bytes, AccountDeserialize, Discriminator},
+};
+
+use super::AccountDiscriminator;
+
+/// Recipe is one of the ingredient lists that can be burned to craft a tool of a collection.
+/// The fixed-size header is followed by `ingredients_len` ingredient entries.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Recipe {
+    /// The layout version of the account.
+    pub version: u64,
+
+    /// The collection this recipe crafts tools of.
+    pub collection: Pubkey,
+
+    /// The id of the recipe within its collection.
+    pub id: u64,
+
+    /// The number of ingredient entries stored after the header.
+    pub ingredients_len: u64,
+}
+
+/// A single ingredient of a recipe.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, BorshSerialize, BorshDeserialize)]
+pub struct Ingredient {
+    /// The mint of the ingredient token.
+    pub mint: Pubkey,
+
+    /// The amount of tokens consumed per craft.
+    pub amount: u64,
+
+    /// The share of the amount that is burned, in basis points. The rest is escrowed
+    /// into the treasury token account of the mint.
+    pub burn_basis_points: u64,
+}
+
+impl Ingredient {
+    /// Splits the amount into the (burned, escrowed) parts.
+    pub fn split(&self) -> (u64, u64) {
+        let burned = (self.amount as u128)
+            .saturating_mul(self.burn_basis_points.min(MAX_BASIS_POINTS) as u128)
+            .checked_div(MAX_BASIS_POINTS as u128)
+            .unwrap_or(0) as u64;
+        (burned, self.amount.saturating_sub(burned))
+    }
+}
+
+impl Recipe {
+    /// Returns the account size of a recipe holding the given number of ingredients.
+    pub fn size(ingredients_len: usize) -> usize {
+        8 + size_of::<Recipe>() + ingredients_len * size_of::<Ingredient>()
+    }
+
+    /// Returns the ingredient entries stored after the recipe header.
+    pub fn ingredients(data: &[u8]) -> Result<&[Ingredient], ProgramError> {
+        let len = Self::try_from_bytes(data)?.ingredients_len as usize;
+        let start = Self::size(0);
+        let end = Self::size(len);
+        if data.len() < end {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        bytemuck::try_cast_slice(&data[start..end]).or(Err(ProgramError::InvalidAccountData))
+    }
+
+    /// Returns the ingredient entries stored after the recipe header, mutably.
+    pub fn ingredients_mut(data: &mut [u8]) -> Result<&mut [Ingredient], ProgramError> {
+        let len = Self::try_from_bytes(data)?.ingredients_len as usize;
+        let start = Self::size(0);
+        let end = Self::size(len);
+        if data.len() < end {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        bytemuck::try_cast_slice_mut(&mut data[start..end]).or(Err(ProgramError::InvalidAccountData))
+    }
+
+    /// Returns true if the recipe was written with the current layout version.
+    pub fn is_current_version(&self) -> bool {
+        self.version.eq(&RECIPE_VERSION)
+    }
+}
+
+impl Discriminator for Recipe {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Recipe.into()
+    }
+}
+
+impl_to_bytes!(Recipe);
+impl_account_from_prefix_bytes!(Recipe);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(amount: u64, burn_basis_points: u64) -> Ingredient {
+        Ingredient {
+            mint: Pubkey::new_unique(),
+            amount,
+            burn_basis_points,
+        }
+    }
+
+    #[test]
+    fn test_split_burn_all() {
+        assert_eq!(ingredient(1_000, MAX_BASIS_POINTS).split(), (1_000, 0));
+    }
+
+    #[test]
+    fn test_split_escrow_all() {
+        assert_eq!(ingredient(1_000, 0).split(), (0, 1_000));
+    }
+
+    #[test]
+    fn test_split_partial_rounds_burn_down() {
+        assert_eq!(ingredient(1_000, 2_500).split(), (250, 750));
+        assert_eq!(ingredient(3, 5_000).split(), (1, 2));
+    }
+
+    #[test]
+    fn test_split_caps_basis_points() {
+        assert_eq!(ingredient(1_000, MAX_BASIS_POINTS + 1).split(), (1_000, 0));
+    }
+
+    #[test]
+    fn test_split_max_amount() {
+        assert_eq!(ingredient(u64::MAX, 5_000).split(), (u64::MAX / 2, u64::MAX - u64::MAX / 2));
+    }
+}
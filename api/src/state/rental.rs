@@ -0,0 +1,103 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    consts::{MAX_BASIS_POINTS, MAX_NAME_LEN, ROYALTIES_BASIS_POINTS},
+    utils::{impl_account_from_bytes, impl_to_bytes, Discriminator},
+};
+
+use super::AccountDiscriminator;
+
+/// Rental escrows a tool listed for rent by its owner. While rented, the renter holds the tool
+/// in their loadout until the expiry slot.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Rental {
+    /// The wallet the tool is returned to.
+    pub owner: Pubkey,
+
+    /// The escrowed tool asset.
+    pub asset: Pubkey,
+
+    /// The collection of the tool asset.
+    pub collection: Pubkey,
+
+    /// The wallet renting the tool. Zeroes if the tool is not rented.
+    pub renter: Pubkey,
+
+    /// The lamports paid by the renter.
+    pub price: u64,
+
+    /// The number of slots a rental lasts.
+    pub duration: u64,
+
+    /// The slot the current rental expires at.
+    pub expires_slot: u64,
+
+    /// The resource the tool mines.
+    pub resource: [u8; MAX_NAME_LEN],
+}
+
+impl Rental {
+    /// Returns true if the tool is rented out.
+    pub fn is_rented(&self) -> bool {
+        self.renter.ne(&Pubkey::default())
+    }
+
+    /// Returns true if the tool is rented out and the rental has not expired at the given slot.
+    pub fn is_active(&self, slot: u64) -> bool {
+        self.is_rented() && slot.lt(&self.expires_slot)
+    }
+
+    /// Splits the price into the (owner, royalty) parts.
+    pub fn split(&self) -> (u64, u64) {
+        let royalty = (self.price as u128)
+            .saturating_mul(ROYALTIES_BASIS_POINTS as u128)
+            .checked_div(MAX_BASIS_POINTS as u128)
+            .unwrap_or(0) as u64;
+        (self.price.saturating_sub(royalty), royalty)
+    }
+}
+
+impl Discriminator for Rental {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Rental.into()
+    }
+}
+
+impl_to_bytes!(Rental);
+impl_account_from_bytes!(Rental);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rental(price: u64) -> Rental {
+        let mut rental = Rental::zeroed();
+        rental.price = price;
+        rental
+    }
+
+    #[test]
+    fn test_split_royalty() {
+        assert_eq!(rental(10_000).split(), (9_600, 400));
+    }
+
+    #[test]
+    fn test_split_rounds_royalty_down() {
+        assert_eq!(rental(24).split(), (24, 0));
+        assert_eq!(rental(25).split(), (24, 1));
+    }
+
+    #[test]
+    fn test_split_free() {
+        assert_eq!(rental(0).split(), (0, 0));
+    }
+
+    #[test]
+    fn test_split_max_price() {
+        let (owner, royalty) = rental(u64::MAX).split();
+        assert_eq!(royalty, (u64::MAX as u128 * 400 / 10_000) as u64);
+        assert_eq!(owner + royalty, u64::MAX);
+    }
+}
@@ -0,0 +1,137 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    consts::{MAX_INGREDIENTS, MAX_NAME_LEN},
+    utils::{impl_account_from_bytes, impl_to_bytes, Discriminator},
+};
+
+use super::AccountDiscriminator;
+
+/// Tool tracks the live stats of a minted tool asset. The durability attribute on the asset is
+/// only synced when the tool is repaired.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Tool {
+    /// The tool asset.
+    pub asset: Pubkey,
+
+    /// The collection of the tool asset.
+    pub collection: Pubkey,
+
+    /// The mining multiplier of the tool.
+    pub multiplier: u64,
+
+    /// The uses left before the tool breaks.
+    pub durability: u64,
+
+    /// The durability the tool was minted with.
+    pub max_durability: u64,
+
+    /// The id of the recipe the tool was crafted with.
+    pub recipe: u64,
+
+    /// The number of times the tool was enchanted.
+    pub enchants: u64,
+
+    /// The number of ingredients escrowed into the treasury when the tool was crafted.
+    pub escrow_len: u64,
+
+    /// The ingredients escrowed into the treasury when the tool was crafted.
+    pub escrow: [Escrow; MAX_INGREDIENTS],
+
+    /// The resource the tool mines. Copied from the config, so the tool stays usable once its
+    /// collection is retired.
+    pub resource: [u8; MAX_NAME_LEN],
+}
+
+/// An amount of ingredient tokens escrowed into the treasury by a craft.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable)]
+pub struct Escrow {
+    /// The mint of the ingredient token.
+    pub mint: Pubkey,
+
+    /// The amount of tokens escrowed.
+    pub amount: u64,
+}
+
+/// Packs escrowed amounts into a fixed-size list. Extra entries are dropped.
+pub fn pack_escrow(escrow: &[Escrow]) -> (u64, [Escrow; MAX_INGREDIENTS]) {
+    let len = escrow.len().min(MAX_INGREDIENTS);
+    let mut packed = [Escrow::zeroed(); MAX_INGREDIENTS];
+    packed[..len].copy_from_slice(&escrow[..len]);
+    (len as u64, packed)
+}
+
+impl Tool {
+    /// Returns true if the tool has no uses left.
+    pub fn is_broken(&self) -> bool {
+        self.durability.eq(&0)
+    }
+
+    /// Returns the ingredients escrowed when the tool was crafted.
+    pub fn escrow(&self) -> &[Escrow] {
+        &self.escrow[..(self.escrow_len as usize).min(MAX_INGREDIENTS)]
+    }
+
+    /// Scales an amount by the share of durability the tool has left.
+    pub fn scale_by_durability(&self, amount: u64) -> u64 {
+        (amount as u128)
+            .saturating_mul(self.durability.min(self.max_durability) as u128)
+            .checked_div(self.max_durability as u128)
+            .unwrap_or(0) as u64
+    }
+}
+
+impl Discriminator for Tool {
+    fn discriminator() -> u8 {
+        AccountDiscriminator::Tool.into()
+    }
+}
+
+impl_to_bytes!(Tool);
+impl_account_from_bytes!(Tool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(durability: u64, max_durability: u64) -> Tool {
+        let mut tool = Tool::zeroed();
+        tool.durability = durability;
+        tool.max_durability = max_durability;
+        tool
+    }
+
+    #[test]
+    fn test_scale_by_durability_full() {
+        assert_eq!(tool(100, 100).scale_by_durability(1_000), 1_000);
+    }
+
+    #[test]
+    fn test_scale_by_durability_partial_rounds_down() {
+        assert_eq!(tool(50, 100).scale_by_durability(1_000), 500);
+        assert_eq!(tool(1, 3).scale_by_durability(1_000), 333);
+    }
+
+    #[test]
+    fn test_scale_by_durability_broken() {
+        assert_eq!(tool(0, 100).scale_by_durability(1_000), 0);
+    }
+
+    #[test]
+    fn test_scale_by_durability_zero_max() {
+        assert_eq!(tool(0, 0).scale_by_durability(1_000), 0);
+    }
+
+    #[test]
+    fn test_scale_by_durability_caps_at_max() {
+        assert_eq!(tool(150, 100).scale_by_durability(1_000), 1_000);
+    }
+
+    #[test]
+    fn test_scale_by_durability_max_amount() {
+        assert_eq!(tool(u64::MAX, u64::MAX).scale_by_durability(u64::MAX), u64::MAX);
+    }
+}
@@ -0,0 +1,67 @@
+use forge_api::{
+    consts::INITIALIZER_ADDRESS,
+    error::ForgeError,
+    instruction::CloseConfigV1Args,
+    loaders::{load_config, load_recipe, load_signer},
+    state::Config,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::utils::{close_pda, AccountDeserialize};
+
+/// Retire a collection by closing its config and all of its recipes, returning their rent to the
+/// destination. Tools already minted are left untouched. Errors while any committed craft
+/// is left to reveal or forfeit.
+pub fn process_close_config<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    _args: CloseConfigV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 4 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, recipe_accounts) = accounts.split_at(4);
+    let [signer, collection_info, config_info, destination_info] = required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_config(config_info, *collection_info.key, true)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check the rent is not returned to an account being closed.
+    if destination_info.key.eq(config_info.key)
+        || recipe_accounts.iter().any(|recipe_info| recipe_info.key.eq(destination_info.key))
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Check no committed craft is left to reveal.
+    let config = *Config::try_from_bytes(&config_info.data.borrow())?;
+    if config.commitments.gt(&0) {
+        return Err(ForgeError::CommitmentPending.into());
+    }
+
+    // Close every recipe, in order of id, as they cannot be closed once the config is gone.
+    if (recipe_accounts.len() as u64).ne(&config.recipes) {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    for (id, recipe_info) in recipe_accounts.iter().enumerate() {
+        load_recipe(recipe_info, *collection_info.key, id as u64, true)?;
+        close_pda(recipe_info, destination_info)?;
+    }
+
+    // Close config.
+    close_pda(config_info, destination_info)?;
+
+    Ok(())
+}
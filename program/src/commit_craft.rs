@@ -0,0 +1,102 @@
+use std::mem::size_of;
+
+use forge_api::{
+    consts::{COMMITMENT_SEED, COMMITMENT_VERSION, REVEAL_DELAY_SLOTS, REVEAL_WINDOW_SLOTS},
+    error::ForgeError,
+    instruction::CommitCraftV1Args,
+    loaders::{load_active_config, load_commitment, load_program, load_recipe, load_signer, load_treasury, load_uninitialized_pda},
+    state::{pack_escrow, Commitment, Config},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    craft::{consume_ingredients, pay_fee, record_receipt, release_craft, reserve_craft},
+    utils::{create_pda, AccountDeserialize, Discriminator},
+};
+
+/// Consume the ingredients of a craft and commit to rolling its attributes from a future slot hash.
+pub fn process_commit_craft<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: CommitCraftV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, remaining_accounts) = accounts.split_at(10);
+    let [signer, collection_info, config_info, recipe_info, receipt_info, commitment_info, treasury_info, token_program, associated_token_program, system_program] = required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_active_config(config_info, *collection_info.key, true)?;
+    load_recipe(recipe_info, *collection_info.key, args.recipe_id, false)?;
+    load_treasury(treasury_info, true)?;
+    load_program(token_program, spl_token::ID)?;
+    load_program(associated_token_program, spl_associated_token_account::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Create the commitment, or forfeit an expired one.
+    let clock = Clock::get()?;
+    if commitment_info.data_is_empty() {
+        load_uninitialized_pda(
+            commitment_info,
+            &[COMMITMENT_SEED, collection_info.key.as_ref(), signer.key.as_ref()],
+            args.commitment_bump,
+            &forge_api::id(),
+        )?;
+        create_pda(
+            commitment_info,
+            &forge_api::id(),
+            8 + size_of::<Commitment>(),
+            &[COMMITMENT_SEED, collection_info.key.as_ref(), signer.key.as_ref(), &[args.commitment_bump]],
+            system_program,
+            signer,
+        )?;
+        let mut commitment_data = commitment_info.data.borrow_mut();
+        commitment_data[0] = Commitment::discriminator() as u8;
+        let mut config_data = config_info.data.borrow_mut();
+        let config = Config::try_from_bytes_mut(&mut config_data)?;
+        config.commitments = config.commitments.saturating_add(1);
+    } else {
+        load_commitment(commitment_info, *collection_info.key, *signer.key, true)?;
+        if !Commitment::try_from_bytes(&commitment_info.data.borrow())?.is_expired(clock.slot) {
+            return Err(ForgeError::CommitmentPending.into());
+        }
+        release_craft(config_info, receipt_info, collection_info, signer.key)?;
+    }
+
+    // Craft.
+    let (wallet_limit, fee, ingredients) = reserve_craft(config_info, recipe_info, signer, &args.proof)?;
+    record_receipt(receipt_info, collection_info, signer, system_program, wallet_limit, args.receipt_bump)?;
+    pay_fee(signer, treasury_info, system_program, fee)?;
+    let escrow = consume_ingredients(
+        &ingredients,
+        remaining_accounts,
+        signer,
+        treasury_info,
+        token_program,
+        associated_token_program,
+        system_program,
+    )?;
+
+    // Record the commitment.
+    let mut commitment_data = commitment_info.data.borrow_mut();
+    let commitment = Commitment::try_from_bytes_mut(&mut commitment_data)?;
+    commitment.version = COMMITMENT_VERSION;
+    commitment.authority = *signer.key;
+    commitment.collection = *collection_info.key;
+    commitment.target_slot = clock.slot.saturating_add(REVEAL_DELAY_SLOTS);
+    commitment.deadline_slot = commitment.target_slot.saturating_add(REVEAL_WINDOW_SLOTS);
+    commitment.recipe = args.recipe_id;
+    (commitment.escrow_len, commitment.escrow) = pack_escrow(&escrow);
+
+    Ok(())
+}
@@ -0,0 +1,355 @@
+use std::mem::size_of;
+
+use forge_api::{
+	consts::{COAL_UPDATE_AUTHORITY, COLLECTION_AUTHORITY_ADDRESS, COLLECTION_AUTHORITY_SEED, RECEIPT_SEED, TOOL_SEED},
+	error::ForgeError,
+	loaders::{load_receipt, load_token_account, load_treasury_token_account, load_uninitialized_pda},
+	merkle,
+	pricing,
+	rarity::Roll,
+	state::{pack_escrow, Config, Escrow, Ingredient, Receipt, Recipe, Tool},
+};
+use forge_utils::{create_pda, spl::{self, burn, create_ata}, AccountDeserialize, Discriminator};
+use solana_program::{
+	account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, program::invoke, program_error::ProgramError,
+	pubkey::Pubkey, system_instruction::transfer, sysvar::Sysvar
+};
+use mpl_core::{
+	instructions::CreateV2CpiBuilder, types::{Attribute, Attributes, PermanentBurnDelegate, PermanentFreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair}, Collection
+};
+
+/// Checks the crafting window, allowlist and supply, and records the craft against the supply.
+/// Returns the collection's wallet limit and fee, and the recipe priced for this craft.
+pub(crate) fn reserve_craft(
+	config_info: &AccountInfo,
+	recipe_info: &AccountInfo,
+	signer: &AccountInfo,
+	proof: &[[u8; 32]],
+) -> Result<(u64, u64, Vec<Ingredient>), ProgramError> {
+	let mut config_data = config_info.data.borrow_mut();
+	let config = Config::try_from_bytes_mut(&mut config_data)?;
+	let clock = Clock::get()?;
+	check_craft(config, signer, proof, clock.unix_timestamp)?;
+	let recipe_data = recipe_info.data.borrow();
+	if !config.is_craft_recipe(Recipe::try_from_bytes(&recipe_data)?.id) {
+		return Err(ForgeError::InvalidRecipe.into());
+	}
+	let ingredients = pricing::quote(config, Recipe::ingredients(&recipe_data)?, clock.unix_timestamp);
+	config.minted = config.minted.saturating_add(1);
+
+	Ok((config.wallet_limit, config.fee, ingredients))
+}
+
+/// Checks the crafting window, allowlist and supply of a collection.
+pub(crate) fn check_craft(config: &Config, signer: &AccountInfo, proof: &[[u8; 32]], now: i64) -> ProgramResult {
+	if config.is_before_start(now) {
+		return Err(ForgeError::CraftingNotStarted.into());
+	}
+	if config.is_after_end(now) {
+		return Err(ForgeError::CraftingEnded.into());
+	}
+	if config.has_allowlist() && !merkle::verify(&config.merkle_root, proof, signer.key) {
+		return Err(ForgeError::NotAllowlisted.into());
+	}
+	if config.remaining_supply().eq(&Some(0)) {
+		return Err(ForgeError::MaxSupplyReached.into());
+	}
+
+	Ok(())
+}
+
+/// Releases the supply and wallet quota reserved by a forfeited craft.
+pub(crate) fn release_craft(
+	config_info: &AccountInfo,
+	receipt_info: &AccountInfo,
+	collection_info: &AccountInfo,
+	authority: &Pubkey,
+) -> ProgramResult {
+	let mut config_data = config_info.data.borrow_mut();
+	let config = Config::try_from_bytes_mut(&mut config_data)?;
+	config.minted = config.minted.saturating_sub(1);
+
+	// The receipt only exists if the collection limited crafts when the wallet first crafted.
+	let receipt_address = Pubkey::find_program_address(&[RECEIPT_SEED, collection_info.key.as_ref(), authority.as_ref()], &forge_api::id()).0;
+	if receipt_info.key.ne(&receipt_address) {
+		return Err(ProgramError::InvalidSeeds);
+	}
+	if receipt_info.data_is_empty() {
+		return Ok(());
+	}
+	load_receipt(receipt_info, *collection_info.key, *authority, true)?;
+	let mut receipt_data = receipt_info.data.borrow_mut();
+	let receipt = Receipt::try_from_bytes_mut(&mut receipt_data)?;
+	receipt.minted = receipt.minted.saturating_sub(1);
+
+	Ok(())
+}
+
+/// Checks and records the signer's crafts, if the collection limits them. The receipt is
+/// created on the signer's first craft.
+pub(crate) fn record_receipt<'info>(
+	receipt_info: &AccountInfo<'info>,
+	collection_info: &AccountInfo<'info>,
+	signer: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	wallet_limit: u64,
+	receipt_bump: u8,
+) -> ProgramResult {
+	if wallet_limit.eq(&0) {
+		return Ok(());
+	}
+
+	if receipt_info.data_is_empty() {
+		load_uninitialized_pda(
+			receipt_info,
+			&[RECEIPT_SEED, collection_info.key.as_ref(), signer.key.as_ref()],
+			receipt_bump,
+			&forge_api::id(),
+		)?;
+		create_pda(
+			receipt_info,
+			&forge_api::id(),
+			8 + size_of::<Receipt>(),
+			&[RECEIPT_SEED, collection_info.key.as_ref(), signer.key.as_ref(), &[receipt_bump]],
+			system_program,
+			signer,
+		)?;
+		let mut receipt_data = receipt_info.data.borrow_mut();
+		receipt_data[0] = Receipt::discriminator() as u8;
+		let receipt = Receipt::try_from_bytes_mut(&mut receipt_data)?;
+		receipt.authority = *signer.key;
+		receipt.collection = *collection_info.key;
+	} else {
+		load_receipt(receipt_info, *collection_info.key, *signer.key, true)?;
+	}
+
+	let mut receipt_data = receipt_info.data.borrow_mut();
+	let receipt = Receipt::try_from_bytes_mut(&mut receipt_data)?;
+	if receipt.minted.ge(&wallet_limit) {
+		return Err(ForgeError::WalletLimitReached.into());
+	}
+	receipt.minted = receipt.minted.saturating_add(1);
+
+	Ok(())
+}
+
+/// Pays the crafting fee into the treasury.
+pub(crate) fn pay_fee<'info>(
+	signer: &AccountInfo<'info>,
+	treasury_info: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	fee: u64,
+) -> ProgramResult {
+	if fee.eq(&0) {
+		return Ok(());
+	}
+
+	invoke(
+		&transfer(signer.key, treasury_info.key, fee),
+		&[
+			signer.clone(),
+			treasury_info.clone(),
+			system_program.clone(),
+		],
+	)
+}
+
+/// Burns or escrows the ingredients of a craft. Each ingredient expects a (mint, token account,
+/// treasury token account) triple in the ingredient accounts. Returns the amounts escrowed.
+pub(crate) fn consume_ingredients<'info>(
+	ingredients: &[Ingredient],
+	ingredient_accounts: &[AccountInfo<'info>],
+	signer: &AccountInfo<'info>,
+	treasury_info: &AccountInfo<'info>,
+	token_program: &AccountInfo<'info>,
+	associated_token_program: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+) -> Result<Vec<Escrow>, ProgramError> {
+	if ingredient_accounts.len() < ingredients.len() * 3 {
+		return Err(ProgramError::NotEnoughAccountKeys);
+	}
+
+	let mut escrow = Vec::with_capacity(ingredients.len());
+
+	for (ingredient, accounts) in ingredients.iter().zip(ingredient_accounts.chunks(3)) {
+		msg!("Ingredient: {:?}, amount: {:?}", ingredient.mint, ingredient.amount);
+		let [mint_info, ingredient_tokens_info, treasury_tokens_info] = accounts else {
+			return Err(ProgramError::NotEnoughAccountKeys);
+		};
+
+		if ingredient.mint.ne(mint_info.key) {
+			return Err(ProgramError::InvalidAccountData);
+		}
+
+		load_token_account(ingredient_tokens_info, Some(signer.key), &ingredient.mint, true)?;
+		let (burn_amount, escrow_amount) = ingredient.split();
+
+		// Burn ingredient tokens
+		if burn_amount.gt(&0) {
+			burn(
+				ingredient_tokens_info, 
+				mint_info,
+				signer,
+				token_program,
+				burn_amount
+			)?;
+		}
+
+		// Escrow ingredient tokens into the treasury
+		if escrow_amount.gt(&0) {
+			if treasury_tokens_info.data_is_empty() {
+				create_ata(
+					signer,
+					treasury_info,
+					treasury_tokens_info,
+					mint_info,
+					system_program,
+					token_program,
+					associated_token_program,
+				)?;
+			}
+			load_treasury_token_account(treasury_tokens_info, ingredient.mint, true)?;
+
+			spl::transfer(
+				signer,
+				ingredient_tokens_info,
+				treasury_tokens_info,
+				token_program,
+				escrow_amount,
+			)?;
+			escrow.push(Escrow {
+				mint: ingredient.mint,
+				amount: escrow_amount,
+			});
+		}
+	}
+
+	Ok(escrow)
+}
+
+/// Creates the tool asset in the collection with the rolled attributes, and the tool account
+/// tracking its live stats.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_tool<'info>(
+	asset_info: &AccountInfo<'info>,
+	tool_info: &AccountInfo<'info>,
+	collection_info: &AccountInfo<'info>,
+	collection_authority: &AccountInfo<'info>,
+	owner: &AccountInfo<'info>,
+	mpl_core_program: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	config: &Config,
+	roll: Roll,
+	recipe_id: u64,
+	escrow: &[Escrow],
+	tool_bump: u8,
+	collection_authority_bump: u8,
+) -> ProgramResult {
+	load_uninitialized_pda(
+		tool_info,
+		&[TOOL_SEED, asset_info.key.as_ref()],
+		tool_bump,
+		&forge_api::id(),
+	)?;
+	create_pda(
+		tool_info,
+		&forge_api::id(),
+		8 + size_of::<Tool>(),
+		&[TOOL_SEED, asset_info.key.as_ref(), &[tool_bump]],
+		system_program,
+		owner,
+	)?;
+	{
+		let mut tool_data = tool_info.data.borrow_mut();
+		tool_data[0] = Tool::discriminator() as u8;
+		let tool = Tool::try_from_bytes_mut(&mut tool_data)?;
+		tool.asset = *asset_info.key;
+		tool.collection = *collection_info.key;
+		tool.multiplier = roll.multiplier;
+		tool.durability = roll.durability;
+		tool.max_durability = roll.durability;
+		tool.recipe = recipe_id;
+		(tool.escrow_len, tool.escrow) = pack_escrow(escrow);
+		tool.resource = config.resource;
+	}
+
+	let attribute_list = vec![
+		Attribute {
+			key: "multiplier".to_string(),
+			value: roll.multiplier.to_string(),
+		},
+		Attribute {
+			key: "rarity".to_string(),
+			value: roll.rarity,
+		},
+		Attribute {
+			key: "resource".to_string(),
+			value: config.resource(),
+		},
+		Attribute {
+			key: "durability".to_string(),
+			value: roll.durability.to_string(),
+		},
+	];
+
+	let collection: Box<Collection> = {
+		let collection_data = collection_info.data.borrow();
+		Collection::from_bytes(&collection_data).unwrap()
+	};
+	let royalties_plugin = collection.plugin_list.royalties.unwrap();
+
+	let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[collection_authority_bump]];
+
+	// Breaking change: the attributes are held by the collection authority rather than the COAL
+	// update authority, so the forge keeps them in sync with the tool account. COAL can no longer
+	// write them, and wears tools down through UseToolV1 instead. Tools minted before this hand
+	// their attributes over to the collection authority on their first repair or enchant.
+	let mut plugins = vec![
+		PluginAuthorityPair {
+			plugin: Plugin::Attributes(Attributes {
+				attribute_list
+			}),
+			authority: Some(PluginAuthority::Address {
+				address: COLLECTION_AUTHORITY_ADDRESS,
+			}),
+		},
+		PluginAuthorityPair {
+			plugin: Plugin::Royalties(royalties_plugin.royalties),
+			authority: Some(PluginAuthority::Address {
+				address: COAL_UPDATE_AUTHORITY,
+			}),
+		},
+	];
+
+	// Tools can be burned by the collection authority once they are worn out.
+	plugins.push(PluginAuthorityPair {
+		plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+		authority: Some(PluginAuthority::Address {
+			address: COLLECTION_AUTHORITY_ADDRESS,
+		}),
+	});
+
+	// Soulbound tools are frozen to their owner for good, unless an admin unfreezes them.
+	if config.soulbound.gt(&0) {
+		plugins.push(PluginAuthorityPair {
+			plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+				frozen: true,
+			}),
+			authority: Some(PluginAuthority::Address {
+				address: COLLECTION_AUTHORITY_ADDRESS,
+			}),
+		});
+	}
+
+	CreateV2CpiBuilder::new(mpl_core_program)
+		.asset(asset_info)
+		.collection(Some(collection_info))
+		.payer(owner)
+		.owner(Some(owner))
+		.name(config.name())
+		.uri(config.uri())
+		.authority(Some(collection_authority))
+		.plugins(plugins)
+		.system_program(system_program)
+		.invoke_signed(&[collection_authority_seeds])
+}
@@ -0,0 +1,128 @@
+use forge_api::{
+    consts::{MAX_BASIS_POINTS, NO_RECIPE, TREASURY, TREASURY_BUMP},
+    error::ForgeError,
+    instruction::DismantleV1Args,
+    loaders::{load_active_config, load_program, load_signer, load_token_account, load_tool, load_treasury, load_treasury_token_account},
+    state::{Config, Tool},
+};
+use mpl_core::instructions::BurnV1CpiBuilder;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    tool::load_tool_asset,
+    utils::{close_pda, spl::{create_ata, transfer_signed}, AccountDeserialize},
+};
+
+/// Burn a tool and refund part of the ingredients its craft escrowed from the treasury. The refund
+/// is the collection's salvage share of each escrowed amount, scaled by the durability the tool has
+/// left, so it never exceeds what the craft paid into the treasury. Tools with nothing to refund,
+/// such as those crafted from fully burned recipes, cannot be dismantled.
+pub fn process_dismantle<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    _args: DismantleV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, remaining_accounts) = accounts.split_at(10);
+    let [signer, asset_info, tool_info, collection_info, config_info, treasury_info, mpl_core_program, token_program, associated_token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_active_config(config_info, *collection_info.key, false)?;
+    load_tool(tool_info, *asset_info.key, true)?;
+    load_treasury(treasury_info, false)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(token_program, spl_token::ID)?;
+    load_program(associated_token_program, spl_associated_token_account::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    // Check the collection salvages tools.
+    let salvage_basis_points = Config::try_from_bytes(&config_info.data.borrow())?.salvage_basis_points;
+    if salvage_basis_points.eq(&0) {
+        return Err(ForgeError::NotSalvageable.into());
+    }
+    let tool = *Tool::try_from_bytes(&tool_info.data.borrow())?;
+    if tool.recipe.eq(&NO_RECIPE) {
+        return Err(ForgeError::NotSalvageable.into());
+    }
+
+    // Check there is something to refund.
+    let escrow = tool.escrow();
+    let refunds = escrow
+        .iter()
+        .map(|escrowed| {
+            let salvaged = (escrowed.amount as u128)
+                .saturating_mul(salvage_basis_points as u128)
+                .checked_div(MAX_BASIS_POINTS as u128)
+                .unwrap_or(0) as u64;
+            tool.scale_by_durability(salvaged)
+        })
+        .collect::<Vec<_>>();
+    if refunds.iter().all(|refund| refund.eq(&0)) {
+        return Err(ForgeError::NotSalvageable.into());
+    }
+    if remaining_accounts.len() < escrow.len() * 3 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Burn the tool, returning the rent of its tool account to the signer.
+    BurnV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(signer))
+        .system_program(Some(system_program))
+        .invoke()?;
+    close_pda(tool_info, signer)?;
+
+    // Refund escrowed ingredients from the treasury.
+    let treasury_seeds = &[TREASURY, &[TREASURY_BUMP]];
+    for ((escrowed, refund), accounts) in escrow.iter().zip(refunds).zip(remaining_accounts.chunks(3)) {
+        let [mint_info, ingredient_tokens_info, treasury_tokens_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if escrowed.mint.ne(mint_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if refund.eq(&0) {
+            continue;
+        }
+
+        if ingredient_tokens_info.data_is_empty() {
+            create_ata(
+                signer,
+                signer,
+                ingredient_tokens_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        }
+        load_token_account(ingredient_tokens_info, Some(signer.key), &escrowed.mint, true)?;
+        load_treasury_token_account(treasury_tokens_info, escrowed.mint, true)?;
+
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            ingredient_tokens_info,
+            token_program,
+            refund,
+            &[treasury_seeds],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use forge_api::{
+    error::ForgeError,
+    instruction::EnchantV1Args,
+    loaders::{load_active_config, load_mint, load_program, load_signer, load_token_account, load_tool},
+    state::{Config, Tool},
+};
+use mpl_core::types::Attribute;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    tool::{load_tool_asset, set_attributes},
+    utils::{spl::burn, AccountDeserialize},
+};
+
+/// Burn the cost of one of the collection's enchantments to raise its bonus attribute on a tool.
+/// Each enchant takes one of the tool's enchant slots.
+pub fn process_enchant<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: EnchantV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, tool_info, collection_info, collection_authority, config_info, mint_info, tokens_info, mpl_core_program, token_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_active_config(config_info, *collection_info.key, false)?;
+    load_tool(tool_info, *asset_info.key, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(token_program, spl_token::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    let asset = load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    let enchantment = config
+        .enchantments()
+        .get(args.enchantment as usize)
+        .ok_or(ForgeError::InvalidEnchantments)?;
+    let name = enchantment.name();
+    load_mint(mint_info, enchantment.mint, true)?;
+    load_token_account(tokens_info, Some(signer.key), &enchantment.mint, true)?;
+
+    // Take an enchant slot.
+    let mut tool_data = tool_info.data.borrow_mut();
+    let tool = Tool::try_from_bytes_mut(&mut tool_data)?;
+    if tool.enchants.ge(&config.enchant_slots) {
+        return Err(ForgeError::NoEnchantSlots.into());
+    }
+    tool.enchants = tool.enchants.saturating_add(1);
+
+    // Raise the attribute. The multiplier is tracked by the tool account, and other attributes
+    // only on the asset.
+    let current = if name.eq("multiplier") {
+        tool.multiplier
+    } else {
+        asset
+            .plugin_list
+            .attributes
+            .as_ref()
+            .and_then(|plugin| plugin.attributes.attribute_list.iter().find(|a| a.key.eq(&name)))
+            .and_then(|attribute| attribute.value.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    if current.ge(&enchantment.max_value) {
+        return Err(ForgeError::EnchantmentMaxed.into());
+    }
+    let value = current.saturating_add(enchantment.bonus).min(enchantment.max_value);
+    if name.eq("multiplier") {
+        tool.multiplier = value;
+    }
+
+    // Burn the enchantment cost.
+    if enchantment.cost.gt(&0) {
+        burn(
+            tokens_info,
+            mint_info,
+            signer,
+            token_program,
+            enchantment.cost,
+        )?;
+    }
+
+    set_attributes(
+        asset_info,
+        collection_info,
+        collection_authority,
+        signer,
+        mpl_core_program,
+        system_program,
+        &asset,
+        vec![Attribute {
+            key: name,
+            value: value.to_string(),
+        }],
+        args.collection_authority_bump,
+    )
+}
@@ -0,0 +1,57 @@
+use forge_api::{
+    consts::COLLECTION_AUTHORITY_ADDRESS,
+    instruction::EquipV1Args,
+    loaders::{load_program, load_signer, load_tool},
+    state::Tool,
+};
+use mpl_core::{
+    instructions::AddPluginV1CpiBuilder,
+    types::{FreezeDelegate, Plugin, PluginAuthority},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    tool::{load_tool_asset, record_loadout},
+    utils::AccountDeserialize,
+};
+
+/// Equip a tool for its resource in the signer's loadout. The tool is frozen until it is
+/// unequipped, so it cannot be sold while in use.
+pub fn process_equip<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: EquipV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, tool_info, collection_info, loadout_info, mpl_core_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_tool(tool_info, *asset_info.key, false)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    // Record the tool for its resource.
+    let resource = Tool::try_from_bytes(&tool_info.data.borrow())?.resource;
+    record_loadout(loadout_info, signer, system_program, resource, asset_info.key, 0, args.loadout_bump)?;
+
+    // Freeze the tool, delegating the thaw to the collection authority.
+    AddPluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(signer))
+        .system_program(system_program)
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .init_authority(PluginAuthority::Address {
+            address: COLLECTION_AUTHORITY_ADDRESS,
+        })
+        .invoke()
+}
@@ -0,0 +1,50 @@
+use forge_api::{
+    error::ForgeError,
+    instruction::ForfeitCommitmentV1Args,
+    loaders::{load_commitment, load_config, load_signer},
+    state::{Commitment, Config},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{craft::release_craft, utils::{close_pda, AccountDeserialize}};
+
+/// Close a committed craft that was not revealed in time. Anyone can forfeit an expired
+/// commitment. Its rent is returned to the authority, the supply and wallet quota it reserved are
+/// released, and the escrowed ingredients are kept by the treasury.
+pub fn process_forfeit_commitment<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    _args: ForfeitCommitmentV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, authority_info, collection_info, config_info, receipt_info, commitment_info] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_config(config_info, *collection_info.key, true)?;
+    load_commitment(commitment_info, *collection_info.key, *authority_info.key, true)?;
+
+    // Check the reveal window has passed.
+    let clock = Clock::get()?;
+    if !Commitment::try_from_bytes(&commitment_info.data.borrow())?.is_expired(clock.slot) {
+        return Err(ForgeError::CommitmentPending.into());
+    }
+
+    // Close the commitment, returning its rent to the authority.
+    close_pda(commitment_info, authority_info)?;
+
+    // Release the supply and wallet quota reserved by the craft.
+    release_craft(config_info, receipt_info, collection_info, authority_info.key)?;
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    config.commitments = config.commitments.saturating_sub(1);
+
+    Ok(())
+}
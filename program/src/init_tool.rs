@@ -0,0 +1,90 @@
+use std::mem::size_of;
+
+use forge_api::{
+    consts::{COLLECTION_AUTHORITY_ADDRESS, MAX_NAME_LEN, NO_RECIPE, TOOL_SEED},
+    error::ForgeError,
+    instruction::InitToolV1Args,
+    loaders::{load_program, load_signer, load_uninitialized_pda},
+    state::Tool,
+};
+use mpl_core::{types::UpdateAuthority, Asset, Collection};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::utils::{create_pda, string_to_bytes, AccountDeserialize, Discriminator};
+
+/// Backfill the tool account of an asset minted before tools were tracked, from the attributes
+/// on the asset. Anyone can init a tool, paying its rent.
+pub fn process_init_tool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: InitToolV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, tool_info, collection_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_uninitialized_pda(
+        tool_info,
+        &[TOOL_SEED, asset_info.key.as_ref()],
+        args.tool_bump,
+        &forge_api::id(),
+    )?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check the collection is managed by the forge, and the asset is in it.
+    if collection_info.owner.ne(&mpl_core::ID) || asset_info.owner.ne(&mpl_core::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let collection = Collection::from_bytes(&collection_info.data.borrow()).or(Err(ProgramError::InvalidAccountData))?;
+    if collection.base.update_authority.ne(&COLLECTION_AUTHORITY_ADDRESS) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let asset = Asset::from_bytes(&asset_info.data.borrow()).or(Err(ProgramError::InvalidAccountData))?;
+    if asset.base.update_authority.ne(&UpdateAuthority::Collection(*collection_info.key)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Read the tool's stats from its attributes.
+    let attribute_list = asset
+        .plugin_list
+        .attributes
+        .map(|plugin| plugin.attributes.attribute_list)
+        .unwrap_or_default();
+    let attribute = |key: &str| {
+        attribute_list
+            .iter()
+            .find(|attribute| attribute.key.eq(key))
+            .map(|attribute| attribute.value.clone())
+            .ok_or(ForgeError::InvalidMetadata)
+    };
+    let multiplier = attribute("multiplier")?.parse::<u64>().or(Err(ForgeError::InvalidMetadata))?;
+    let durability = attribute("durability")?.parse::<u64>().or(Err(ForgeError::InvalidMetadata))?;
+    let resource = string_to_bytes::<MAX_NAME_LEN>(&attribute("resource")?).ok_or(ForgeError::InvalidResource)?;
+
+    // Initialize the tool. No ingredients were escrowed for it, so it cannot be salvaged.
+    create_pda(
+        tool_info,
+        &forge_api::id(),
+        8 + size_of::<Tool>(),
+        &[TOOL_SEED, asset_info.key.as_ref(), &[args.tool_bump]],
+        system_program,
+        signer,
+    )?;
+    let mut tool_data = tool_info.data.borrow_mut();
+    tool_data[0] = Tool::discriminator() as u8;
+    let tool = Tool::try_from_bytes_mut(&mut tool_data)?;
+    tool.asset = *asset_info.key;
+    tool.collection = *collection_info.key;
+    tool.multiplier = multiplier;
+    tool.durability = durability;
+    tool.max_durability = durability;
+    tool.recipe = NO_RECIPE;
+    tool.resource = resource;
+
+    Ok(())
+}
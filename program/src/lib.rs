@@ -1,14 +1,58 @@
 
 
+mod craft;
 mod new;
 mod mint;
 mod initialize;
 mod verify;
+mod update_config;
+mod new_recipe;
+mod update_recipe;
+mod commit_craft;
+mod reveal_craft;
+mod forfeit_commitment;
+mod close_config;
+mod use_tool;
+mod init_tool;
+mod repair;
+mod dismantle;
+mod reforge;
+mod enchant;
+mod unfreeze;
+mod migrate_config;
+mod equip;
+mod unequip;
+mod list_tool;
+mod rent_tool;
+mod return_tool;
+mod reap;
+mod tool;
 
 use new::*;
 use mint::*;
 use initialize::*;
 use verify::*;
+use update_config::*;
+use new_recipe::*;
+use update_recipe::*;
+use commit_craft::*;
+use reveal_craft::*;
+use forfeit_commitment::*;
+use close_config::*;
+use use_tool::*;
+use init_tool::*;
+use repair::*;
+use dismantle::*;
+use reforge::*;
+use enchant::*;
+use unfreeze::*;
+use migrate_config::*;
+use equip::*;
+use unequip::*;
+use list_tool::*;
+use rent_tool::*;
+use return_tool::*;
+use reap::*;
 
 use forge_api::instruction::*;
 use borsh::BorshDeserialize;
@@ -40,6 +84,27 @@ pub fn process_instruction(
         ForgeInstruction::MintV1(args) => process_mint(accounts, args)?,
         ForgeInstruction::Initialize(args) => process_initialize(accounts, args)?,
         ForgeInstruction::Verify(args) => process_verify(accounts, args)?,
+        ForgeInstruction::UpdateConfigV1(args) => process_update_config(accounts, args)?,
+        ForgeInstruction::NewRecipeV1(args) => process_new_recipe(accounts, args)?,
+        ForgeInstruction::UpdateRecipeV1(args) => process_update_recipe(accounts, args)?,
+        ForgeInstruction::CommitCraftV1(args) => process_commit_craft(accounts, args)?,
+        ForgeInstruction::RevealCraftV1(args) => process_reveal_craft(accounts, args)?,
+        ForgeInstruction::ForfeitCommitmentV1(args) => process_forfeit_commitment(accounts, args)?,
+        ForgeInstruction::CloseConfigV1(args) => process_close_config(accounts, args)?,
+        ForgeInstruction::UnfreezeV1(args) => process_unfreeze(accounts, args)?,
+        ForgeInstruction::MigrateConfigV1(args) => process_migrate_config(accounts, args)?,
+        ForgeInstruction::RepairV1(args) => process_repair(accounts, args)?,
+        ForgeInstruction::DismantleV1(args) => process_dismantle(accounts, args)?,
+        ForgeInstruction::ReforgeV1(args) => process_reforge(accounts, args)?,
+        ForgeInstruction::EnchantV1(args) => process_enchant(accounts, args)?,
+        ForgeInstruction::EquipV1(args) => process_equip(accounts, args)?,
+        ForgeInstruction::UnequipV1(args) => process_unequip(accounts, args)?,
+        ForgeInstruction::ListToolV1(args) => process_list_tool(accounts, args)?,
+        ForgeInstruction::RentToolV1(args) => process_rent_tool(accounts, args)?,
+        ForgeInstruction::ReturnToolV1(args) => process_return_tool(accounts, args)?,
+        ForgeInstruction::ReapV1(args) => process_reap(accounts, args)?,
+        ForgeInstruction::InitToolV1(args) => process_init_tool(accounts, args)?,
+        ForgeInstruction::UseToolV1(args) => process_use_tool(accounts, args)?,
     }
 
     Ok(())
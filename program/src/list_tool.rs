@@ -0,0 +1,80 @@
+use std::mem::size_of;
+
+use forge_api::{
+    consts::RENTAL_SEED,
+    error::ForgeError,
+    instruction::ListToolV1Args,
+    loaders::{load_program, load_signer, load_tool, load_uninitialized_pda},
+    state::{Rental, Tool},
+};
+use mpl_core::instructions::TransferV1CpiBuilder;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    tool::load_tool_asset,
+    utils::{create_pda, AccountDeserialize, Discriminator},
+};
+
+/// List a tool for rent, escrowing it until it is returned.
+pub fn process_list_tool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: ListToolV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, tool_info, collection_info, rental_info, mpl_core_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_tool(tool_info, *asset_info.key, false)?;
+    load_uninitialized_pda(
+        rental_info,
+        &[RENTAL_SEED, asset_info.key.as_ref()],
+        args.rental_bump,
+        &forge_api::id(),
+    )?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    if args.duration.eq(&0) {
+        return Err(ForgeError::InvalidRental.into());
+    }
+
+    // Record the listing.
+    create_pda(
+        rental_info,
+        &forge_api::id(),
+        8 + size_of::<Rental>(),
+        &[RENTAL_SEED, asset_info.key.as_ref(), &[args.rental_bump]],
+        system_program,
+        signer,
+    )?;
+    {
+        let mut rental_data = rental_info.data.borrow_mut();
+        rental_data[0] = Rental::discriminator() as u8;
+        let rental = Rental::try_from_bytes_mut(&mut rental_data)?;
+        rental.owner = *signer.key;
+        rental.asset = *asset_info.key;
+        rental.collection = *collection_info.key;
+        rental.price = args.price;
+        rental.duration = args.duration;
+        rental.resource = Tool::try_from_bytes(&tool_info.data.borrow())?.resource;
+    }
+
+    // Escrow the tool.
+    TransferV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(signer))
+        .new_owner(rental_info)
+        .system_program(Some(system_program))
+        .invoke()
+}
@@ -0,0 +1,100 @@
+use std::mem::size_of;
+
+use forge_api::{
+    consts::{CONFIG_VERSION, INITIALIZER_ADDRESS, RECIPE_SEED},
+    error::ForgeError,
+    instruction::MigrateConfigV1Args,
+    loaders::{load_outdated_config, load_program, load_signer, load_uninitialized_pda},
+    state::{decode_version, Config, LegacyConfig, Recipe},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    new_recipe::{init_recipe, validate_ingredients, write_ingredients},
+    utils::{create_pda, realloc_pda, string_to_bytes, AccountDeserialize},
+};
+
+/// Migrate a config to the current layout version. Versioned configs are zero-extended, leaving
+/// new settings unset. Legacy configs are rewritten with the given tool metadata, and their
+/// ingredients moved into the collection's first recipe.
+pub fn process_migrate_config<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: MigrateConfigV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, additional_accounts) = accounts.split_at(5);
+    let [signer, collection_info, config_info, recipe_info, system_program] = required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_outdated_config(config_info, *collection_info.key, true)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let legacy = decode_version(&config_info.data.borrow())?.eq(&0);
+    let legacy_ingredients = if legacy {
+        let ingredients = LegacyConfig::try_from_bytes(&config_info.data.borrow())?.ingredients();
+        validate_ingredients(&ingredients, additional_accounts)?;
+        ingredients
+    } else {
+        vec![]
+    };
+
+    // Resize the config to the current layout.
+    realloc_pda(config_info, 8 + size_of::<Config>(), system_program, signer)?;
+    {
+        let mut config_data = config_info.data.borrow_mut();
+        if legacy {
+            config_data[8..].fill(0);
+        }
+        let config = Config::try_from_bytes_mut(&mut config_data)?;
+        config.version = CONFIG_VERSION;
+        if legacy {
+            if args.resource.is_empty() {
+                return Err(ForgeError::InvalidResource.into());
+            }
+            config.recipes = 1;
+            config.multiplier = args.multiplier;
+            config.durability = args.durability;
+            config.name = string_to_bytes(&args.name).ok_or(ForgeError::InvalidMetadata)?;
+            config.uri = string_to_bytes(&args.uri).ok_or(ForgeError::InvalidMetadata)?;
+            config.rarity = string_to_bytes(&args.rarity).ok_or(ForgeError::InvalidMetadata)?;
+            config.resource = string_to_bytes(&args.resource).ok_or(ForgeError::InvalidResource)?;
+        }
+    }
+
+    // Move the legacy ingredients into the first recipe.
+    if legacy {
+        load_uninitialized_pda(
+            recipe_info,
+            &[RECIPE_SEED, collection_info.key.as_ref(), &0u64.to_le_bytes()],
+            args.recipe_bump,
+            &forge_api::id(),
+        )?;
+        create_pda(
+            recipe_info,
+            &forge_api::id(),
+            Recipe::size(legacy_ingredients.len()),
+            &[RECIPE_SEED, collection_info.key.as_ref(), &0u64.to_le_bytes(), &[args.recipe_bump]],
+            system_program,
+            signer,
+        )?;
+        init_recipe(recipe_info, *collection_info.key, 0)?;
+        write_ingredients(recipe_info, &legacy_ingredients)?;
+    }
+
+    Ok(())
+}
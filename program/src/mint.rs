@@ -1,149 +1,75 @@
 use forge_api::{
-	consts::{COAL_UPDATE_AUTHORITY, COLLECTION_AUTHORITY_SEED},
 	error::ForgeError,
 	instruction::MintV1Args,
-	loaders::{load_config, load_program, load_signer, load_token_account}, 
+	loaders::{load_active_config, load_program, load_recipe, load_signer, load_treasury},
+	rarity,
 	state::Config
 };
-use forge_utils::{spl::burn, AccountDeserialize};
+use forge_utils::AccountDeserialize;
 use solana_program::{
-  account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError
-};
-use mpl_core::{
-  instructions::CreateV2CpiBuilder, types::{Attribute, Attributes, Plugin, PluginAuthority, PluginAuthorityPair}, Collection
+  account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError
 };
 
+use crate::craft::{consume_ingredients, create_tool, pay_fee, record_receipt, reserve_craft};
+
 pub fn process_mint<'a, 'info>(
   accounts: &'a [AccountInfo<'info>],
   args: MintV1Args,
 ) -> ProgramResult {	
-	let (required_accounts, remaining_accounts) = accounts.split_at(8);
-	let [signer, mint_info, collection_info, collection_authority, config_info, mpl_core_program, token_program, system_program] = required_accounts
+	if accounts.len() < 13 {
+		return Err(ProgramError::NotEnoughAccountKeys);
+	}
+	let (required_accounts, remaining_accounts) = accounts.split_at(13);
+	let [signer, mint_info, collection_info, collection_authority, config_info, recipe_info, receipt_info, tool_info, treasury_info, mpl_core_program, token_program, associated_token_program, system_program] = required_accounts
 	else {
 		return Err(ProgramError::NotEnoughAccountKeys);
 	};
 
 	load_signer(signer)?;
-	load_config(config_info, *collection_info.key, false)?;
+	load_active_config(config_info, *collection_info.key, true)?;
+	load_recipe(recipe_info, *collection_info.key, args.recipe_id, false)?;
+	load_treasury(treasury_info, true)?;
 	load_program(mpl_core_program, mpl_core::ID)?;
 	load_program(token_program, spl_token::ID)?;
+	load_program(associated_token_program, spl_associated_token_account::ID)?;
 	load_program(system_program, solana_program::system_program::ID)?;
 
-	let config_data = config_info.data.borrow();
-	let config = Config::try_from_bytes(&config_data).unwrap();
-
-	for i in 0..config.ingredients.len() {
-		let ingredient = config.ingredients[i];
-		let amount = config.amounts[i];
-		msg!("Ingredient: {:?}, amount: {:?}", ingredient, amount);
-		if amount == 0 {
-			continue;
-		}
-
-		let mint_info = &remaining_accounts[i * 2];
-		let ingredient_tokens_info = &remaining_accounts[i * 2 + 1];
-		
-		if ingredient.ne(&mint_info.key) {
-			return Err(ProgramError::InvalidAccountData);
-		}
-
-		load_token_account(&ingredient_tokens_info, Some(signer.key), &ingredient, true)?;
-		
-		// Burn ingredient tokens
-		burn(
-			ingredient_tokens_info, 
-			mint_info,
-			signer,
-			token_program,
-			amount
-		)?;
+	// Collections with rarity tiers are only crafted through commit and reveal.
+	if !Config::try_from_bytes(&config_info.data.borrow())?.rarity_tiers().is_empty() {
+		return Err(ForgeError::CommitRequired.into());
 	}
 
-	let collection: Box<Collection> = {
-		let collection_data = collection_info.data.borrow();
-		Collection::from_bytes(&collection_data).unwrap()
-	};
-	let royalties_plugin = collection.plugin_list.royalties.unwrap();
-	
-	let mut attribute_list = vec![
-		Attribute {
-			key: "multiplier".to_string(),
-			value: "70".to_string(),
-		},
-		Attribute {
-			key: "rarity".to_string(),
-			value: "common".to_string(),
-		},
-	];
-	
-	match args.resource.as_str() {
-		"coal" => {
-			attribute_list.push(Attribute {
-				key: "resource".to_string(),
-				value: "coal".to_string(),
-			});
-			attribute_list.push(Attribute {
-				key: "durability".to_string(),
-				value: "1000".to_string(),
-			});
-		},
-		"wood" => {
-			attribute_list.push(Attribute {
-				key: "resource".to_string(),
-				value: "wood".to_string(),
-			});
-			attribute_list.push(Attribute {
-				key: "durability".to_string(),
-				value: "100".to_string(),
-			});
-		},
-		_ => {
-			return Err(ForgeError::InvalidResource.into());
-		}
-	};
-	let name = match args.resource.as_str() {
-		"coal" => "Miner's Pickaxe".to_string(),
-		"wood" => "Woodcutter's Axe".to_string(),
-		_ => {
-			return Err(ForgeError::InvalidResource.into());
-		}
-	};
-	let uri = match args.resource.as_str() {
-		"coal" => "https://minechain.gg/metadata.pickaxe.json".to_string(),
-		"wood" => "https://minechain.gg/metadata.axe.json".to_string(),
-		_ => {
-			return Err(ForgeError::InvalidResource.into());
-		}
-	};
-
-	let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[args.collection_authority_bump]];
+	let (wallet_limit, fee, ingredients) = reserve_craft(config_info, recipe_info, signer, &args.proof)?;
+	record_receipt(receipt_info, collection_info, signer, system_program, wallet_limit, args.receipt_bump)?;
+	pay_fee(signer, treasury_info, system_program, fee)?;
+	let escrow = consume_ingredients(
+		&ingredients,
+		remaining_accounts,
+		signer,
+		treasury_info,
+		token_program,
+		associated_token_program,
+		system_program,
+	)?;
 
-  	CreateV2CpiBuilder::new(mpl_core_program)
-		.asset(mint_info)
-		.collection(Some(&collection_info))
-		.payer(signer)
-		.owner(Some(signer))
-		.name(name)
-		.uri(uri)
-		.authority(Some(collection_authority))
-		.plugins(vec![
-			PluginAuthorityPair {
-				plugin: Plugin::Attributes(Attributes {
-					attribute_list
-				}),
-				authority: Some(PluginAuthority::Address {
-					address: COAL_UPDATE_AUTHORITY,
-				}),
-			},
-			PluginAuthorityPair {
-				plugin: Plugin::Royalties(royalties_plugin.royalties),
-				authority: Some(PluginAuthority::Address {
-					address: COAL_UPDATE_AUTHORITY,
-				}),
-			},
-		])
-		.system_program(system_program)
-		.invoke_signed(&[collection_authority_seeds])?;
+	// Mint the tool with the fixed attributes of the config.
+	let config_data = config_info.data.borrow();
+	let config = Config::try_from_bytes(&config_data)?;
+	let roll = rarity::base(config);
 
-  	Ok(())
-}
\ No newline at end of file
+	create_tool(
+		mint_info,
+		tool_info,
+		collection_info,
+		collection_authority,
+		signer,
+		mpl_core_program,
+		system_program,
+		config,
+		roll,
+		args.recipe_id,
+		&escrow,
+		args.tool_bump,
+		args.collection_authority_bump,
+	)
+}
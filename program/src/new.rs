@@ -2,26 +2,33 @@ use std::mem::size_of;
 
 use forge_api::{
 	consts::*,
+	error::ForgeError,
 	instruction::NewV1Args,
-	loaders::{load_collection_authority, load_mint, load_program, load_signer, load_uninitialized_pda},
-	state::Config
+	loaders::{load_collection_authority, load_program, load_signer, load_uninitialized_pda},
+	state::{Config, Recipe}
 };
 use solana_program::{
-  account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError
+  account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, sysvar::Sysvar
 };
 use mpl_core::{
   instructions::CreateCollectionV2CpiBuilder,
   types::{Attribute, Attributes, Creator, Plugin, PluginAuthority, PluginAuthorityPair, Royalties, RuleSet}
 };
 
-use crate::utils::{create_pda, AccountDeserialize, Discriminator};
+use crate::{
+	new_recipe::{init_recipe, validate_ingredients, write_ingredients},
+	utils::{create_pda, string_to_bytes, AccountDeserialize, Discriminator},
+};
 
 pub fn process_new<'a, 'info>(
   accounts: &'a [AccountInfo<'info>],
   args: NewV1Args,
 ) -> ProgramResult {
-	let (required_accounts, additional_accounts) = accounts.split_at(8);
-	let [signer, collection_info, collection_authority, config_info, mpl_core_program, token_program, associated_token_program, system_program] = required_accounts
+	if accounts.len() < 9 {
+		return Err(ProgramError::NotEnoughAccountKeys);
+	}
+	let (required_accounts, additional_accounts) = accounts.split_at(9);
+	let [signer, collection_info, collection_authority, config_info, recipe_info, mpl_core_program, token_program, associated_token_program, system_program] = required_accounts
 	else {
 		return Err(ProgramError::NotEnoughAccountKeys);
 	};
@@ -43,6 +50,16 @@ pub fn process_new<'a, 'info>(
 		args.config_bump,
 		&forge_api::id(),
 	)?;
+	load_uninitialized_pda(
+		recipe_info,
+		&[
+			RECIPE_SEED,
+			collection_info.key.as_ref(),
+			&0u64.to_le_bytes(),
+		],
+		args.recipe_bump,
+		&forge_api::id(),
+	)?;
 	load_program(token_program, spl_token::ID)?;
 	load_program(associated_token_program, spl_associated_token_account::ID)?;
 	load_program(mpl_core_program, mpl_core::ID)?;
@@ -53,6 +70,26 @@ pub fn process_new<'a, 'info>(
 		return Err(ProgramError::MissingRequiredSignature);
 	}
 
+	// Validate metadata.
+	if args.resource.is_empty() {
+		return Err(ForgeError::InvalidResource.into());
+	}
+
+	// Validate crafting window.
+	if args.start_at.gt(&0) && args.end_at.gt(&0) && args.end_at.le(&args.start_at) {
+		return Err(ForgeError::InvalidWindow.into());
+	}
+
+	// Validate launch auction.
+	if args.auction_duration.lt(&0)
+		|| (args.auction_duration.gt(&0)
+			&& (args.auction_floor_basis_points.eq(&0) || args.auction_floor_basis_points.gt(&args.auction_start_basis_points)))
+	{
+		return Err(ForgeError::InvalidAuction.into());
+	}
+
+	validate_ingredients(&args.ingredients, additional_accounts)?;
+
 	// Initialize config.
 	create_pda(
 		config_info,
@@ -62,24 +99,50 @@ pub fn process_new<'a, 'info>(
 		system_program,
 		signer,
 	)?;
-	let mut config_data = config_info.data.borrow_mut();
-	config_data[0] = Config::discriminator() as u8;
-	let config: &mut Config = Config::try_from_bytes_mut(&mut config_data)?;
-	config.amounts = args.amounts;
-	config.ingredients = args.ingredients;
-
-	// Validate mints
-	for i in 0..config.ingredients.len() {
-		let ingredient = config.ingredients[i];
-
-		if ingredient.eq(&solana_program::system_program::ID) {
-			continue;
+	{
+		let mut config_data = config_info.data.borrow_mut();
+		config_data[0] = Config::discriminator() as u8;
+		let config: &mut Config = Config::try_from_bytes_mut(&mut config_data)?;
+		config.version = CONFIG_VERSION;
+		config.recipes = 1;
+		config.multiplier = args.multiplier;
+		config.durability = args.durability;
+		config.name = string_to_bytes(&args.name).ok_or(ForgeError::InvalidMetadata)?;
+		config.uri = string_to_bytes(&args.uri).ok_or(ForgeError::InvalidMetadata)?;
+		config.rarity = string_to_bytes(&args.rarity).ok_or(ForgeError::InvalidMetadata)?;
+		config.resource = string_to_bytes(&args.resource).ok_or(ForgeError::InvalidResource)?;
+		config.max_supply = args.max_supply;
+		config.minted = 0;
+		config.start_at = args.start_at;
+		if args.auction_duration.gt(&0) && args.start_at.eq(&0) {
+			// The auction decays from the moment crafting opens.
+			config.start_at = Clock::get()?.unix_timestamp;
 		}
-
-		let mint_info = &additional_accounts[i];
-		load_mint(mint_info, ingredient, false)?;
+		config.end_at = args.end_at;
+		config.wallet_limit = args.wallet_limit;
+		config.merkle_root = args.merkle_root;
+		config.fee = args.fee;
+		config.curve = u8::from(args.curve) as u64;
+		config.curve_rate = args.curve_rate;
+		config.auction_start_basis_points = args.auction_start_basis_points;
+		config.auction_floor_basis_points = args.auction_floor_basis_points;
+		config.auction_duration = args.auction_duration;
+		config.set_rarity_tiers(&args.rarity_tiers)?;
+		config.soulbound = args.soulbound as u64;
 	}
 
+	// Initialize the first recipe.
+	create_pda(
+		recipe_info,
+		&forge_api::id(),
+		Recipe::size(args.ingredients.len()),
+		&[RECIPE_SEED, collection_info.key.as_ref(), &0u64.to_le_bytes(), &[args.recipe_bump]],
+		system_program,
+		signer,
+	)?;
+	init_recipe(recipe_info, *collection_info.key, 0)?;
+	write_ingredients(recipe_info, &args.ingredients)?;
+
 	let collection_authority_seeds = &[b"collection_authority".as_ref(), &[args.collection_authority_bump]];
 	
 	CreateCollectionV2CpiBuilder::new(mpl_core_program)
@@ -102,11 +165,11 @@ pub fn process_new<'a, 'info>(
 						},
 						Attribute {
 							key: "rarity".to_string(),
-							value: "common".to_string(),
+							value: args.rarity,
 						},
 						Attribute {
 							key: "resource".to_string(),
-							value: "coal".to_string(),
+							value: args.resource,
 						},
 					],
 				}),
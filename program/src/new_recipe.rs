@@ -0,0 +1,129 @@
+use forge_api::{
+    consts::{INITIALIZER_ADDRESS, MAX_BASIS_POINTS, MAX_INGREDIENTS, RECIPE_SEED, RECIPE_VERSION},
+    error::ForgeError,
+    instruction::NewRecipeV1Args,
+    loaders::{load_config, load_mint, load_program, load_signer, load_uninitialized_pda},
+    state::{Config, Ingredient, Recipe},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{create_pda, AccountDeserialize, Discriminator};
+
+/// Add a new recipe to an existing collection.
+pub fn process_new_recipe<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: NewRecipeV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, additional_accounts) = accounts.split_at(5);
+    let [signer, collection_info, config_info, recipe_info, system_program] = required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_config(config_info, *collection_info.key, true)?;
+    load_uninitialized_pda(
+        recipe_info,
+        &[RECIPE_SEED, collection_info.key.as_ref(), &args.id.to_le_bytes()],
+        args.recipe_bump,
+        &forge_api::id(),
+    )?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    validate_ingredients(&args.ingredients, additional_accounts)?;
+
+    // Recipe ids are assigned sequentially.
+    {
+        let mut config_data = config_info.data.borrow_mut();
+        let config = Config::try_from_bytes_mut(&mut config_data)?;
+        if args.id.ne(&config.recipes) {
+            return Err(ProgramError::InvalidArgument);
+        }
+        config.recipes = config.recipes.saturating_add(1);
+    }
+
+    // Initialize recipe.
+    create_pda(
+        recipe_info,
+        &forge_api::id(),
+        Recipe::size(args.ingredients.len()),
+        &[RECIPE_SEED, collection_info.key.as_ref(), &args.id.to_le_bytes(), &[args.recipe_bump]],
+        system_program,
+        signer,
+    )?;
+    init_recipe(recipe_info, *collection_info.key, args.id)?;
+    write_ingredients(recipe_info, &args.ingredients)?;
+
+    Ok(())
+}
+
+/// Errors if:
+/// - There are no ingredients, or more than the maximum.
+/// - An amount is zero.
+/// - A burn share is more than the whole amount.
+/// - A mint account is missing or does not match its ingredient.
+pub(crate) fn validate_ingredients(
+    ingredients: &[Ingredient],
+    mint_infos: &[AccountInfo],
+) -> ProgramResult {
+    if ingredients.is_empty()
+        || ingredients.len() > MAX_INGREDIENTS
+        || mint_infos.len() < ingredients.len()
+    {
+        return Err(ForgeError::InvalidIngredients.into());
+    }
+
+    for (ingredient, mint_info) in ingredients.iter().zip(mint_infos.iter()) {
+        if ingredient.amount == 0 || ingredient.burn_basis_points > MAX_BASIS_POINTS {
+            return Err(ForgeError::InvalidIngredients.into());
+        }
+
+        load_mint(mint_info, ingredient.mint, false)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the header of a freshly created recipe account.
+pub(crate) fn init_recipe(
+    recipe_info: &AccountInfo,
+    collection: Pubkey,
+    id: u64,
+) -> ProgramResult {
+    let mut recipe_data = recipe_info.data.borrow_mut();
+    recipe_data[0] = Recipe::discriminator() as u8;
+    let recipe = Recipe::try_from_bytes_mut(&mut recipe_data)?;
+    recipe.version = RECIPE_VERSION;
+    recipe.collection = collection;
+    recipe.id = id;
+
+    Ok(())
+}
+
+/// Writes the ingredients of a recipe. The account must already be sized to fit them.
+pub(crate) fn write_ingredients(
+    recipe_info: &AccountInfo,
+    ingredients: &[Ingredient],
+) -> ProgramResult {
+    let mut recipe_data = recipe_info.data.borrow_mut();
+    let recipe = Recipe::try_from_bytes_mut(&mut recipe_data)?;
+    recipe.ingredients_len = ingredients.len() as u64;
+
+    Recipe::ingredients_mut(&mut recipe_data)?.copy_from_slice(ingredients);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use forge_api::{
+    consts::{COLLECTION_AUTHORITY_SEED, LOADOUT_SEED},
+    error::ForgeError,
+    instruction::ReapV1Args,
+    loaders::{is_config_retired, load_config, load_loadout, load_program, load_signer, load_tool, load_treasury},
+    state::{Config, Loadout, Tool},
+};
+use mpl_core::instructions::BurnV1CpiBuilder;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    tool::{clear_loadout, load_tool_asset},
+    utils::{close_pda, AccountDeserialize},
+};
+
+/// Burn a worn out tool through its permanent burn delegate. Anyone can reap a tool with no
+/// durability left, and is paid the collection's reap reward from the treasury if it can afford it.
+pub fn process_reap<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: ReapV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, owner_info, asset_info, tool_info, collection_info, collection_authority, config_info, loadout_info, treasury_info, mpl_core_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    let retired = is_config_retired(config_info, *collection_info.key)?;
+    if !retired {
+        load_config(config_info, *collection_info.key, false)?;
+    }
+    load_tool(tool_info, *asset_info.key, true)?;
+    load_treasury(treasury_info, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_tool_asset(asset_info, collection_info, owner_info.key)?;
+
+    // Check the tool is worn out, and not escrowed for rent.
+    if !Tool::try_from_bytes(&tool_info.data.borrow())?.is_broken() {
+        return Err(ForgeError::NotReapable.into());
+    }
+    if owner_info.owner.eq(&forge_api::id()) {
+        return Err(ForgeError::NotReapable.into());
+    }
+
+    // Take the tool out of the owner's loadout, if equipped. The loadout is always the owner's,
+    // and is only skipped if the owner never created one.
+    let loadout_address = Pubkey::find_program_address(&[LOADOUT_SEED, owner_info.key.as_ref()], &forge_api::id()).0;
+    if loadout_info.key.ne(&loadout_address) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !loadout_info.data_is_empty() {
+        load_loadout(loadout_info, *owner_info.key, true)?;
+        let equipped = Loadout::try_from_bytes(&loadout_info.data.borrow())?
+            .slots
+            .iter()
+            .any(|slot| slot.asset.eq(asset_info.key));
+        if equipped {
+            clear_loadout(loadout_info, asset_info.key)?;
+        }
+    }
+
+    // Burn the tool, returning the rent of its tool account to the owner.
+    let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[args.collection_authority_bump]];
+    BurnV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(collection_authority))
+        .system_program(Some(system_program))
+        .invoke_signed(&[collection_authority_seeds])?;
+    close_pda(tool_info, owner_info)?;
+
+    // Pay the reap reward, keeping the treasury rent exempt. Retired collections pay none.
+    if retired {
+        return Ok(());
+    }
+    let reap_reward = Config::try_from_bytes(&config_info.data.borrow())?.reap_reward;
+    let min_balance = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let reward = reap_reward.min(treasury_info.lamports().saturating_sub(min_balance));
+    if reward.gt(&0) {
+        **treasury_info.try_borrow_mut_lamports()? -= reward;
+        **signer.try_borrow_mut_lamports()? += reward;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use forge_api::{
+    consts::NO_RECIPE,
+    error::ForgeError,
+    instruction::ReforgeV1Args,
+    loaders::{load_active_config, load_program, load_recipe, load_signer, load_tool, load_treasury},
+    rarity,
+    state::{Config, Recipe, Tool},
+};
+use mpl_core::instructions::BurnV1CpiBuilder;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    craft::{check_craft, consume_ingredients, create_tool, record_receipt},
+    tool::load_tool_asset,
+    utils::{close_pda, AccountDeserialize},
+};
+
+/// Burn tools of a collection, along with its upgrade recipe, to mint a tool of the collection
+/// they upgrade into. The new tool gets the fixed attributes of the target collection's config,
+/// so collections with rarity tiers cannot be upgraded into.
+pub fn process_reforge<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: ReforgeV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 15 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, remaining_accounts) = accounts.split_at(15);
+    let [signer, mint_info, collection_info, config_info, recipe_info, target_collection_info, collection_authority, target_config_info, target_receipt_info, tool_info, treasury_info, mpl_core_program, token_program, associated_token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_active_config(config_info, *collection_info.key, false)?;
+    load_active_config(target_config_info, *target_collection_info.key, true)?;
+    load_treasury(treasury_info, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(token_program, spl_token::ID)?;
+    load_program(associated_token_program, spl_associated_token_account::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check the upgrade path.
+    let config = *Config::try_from_bytes(&config_info.data.borrow())?;
+    if !config.is_upgradeable() {
+        return Err(ForgeError::NotUpgradeable.into());
+    }
+    if config.upgrade_collection.ne(target_collection_info.key) {
+        return Err(ForgeError::InvalidUpgrade.into());
+    }
+    load_recipe(recipe_info, *collection_info.key, config.upgrade_recipe, false)?;
+    if !Config::try_from_bytes(&target_config_info.data.borrow())?.rarity_tiers().is_empty() {
+        return Err(ForgeError::CommitRequired.into());
+    }
+
+    let inputs_len = (config.upgrade_inputs as usize)
+        .checked_mul(2)
+        .ok_or(ForgeError::InvalidUpgrade)?;
+    if remaining_accounts.len() < inputs_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (input_accounts, ingredient_accounts) = remaining_accounts.split_at(inputs_len);
+
+    // Burn the input tools, after checking they were forged in the collection.
+    for accounts in input_accounts.chunks(2) {
+        let [asset_info, asset_tool_info] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        load_tool(asset_tool_info, *asset_info.key, true)?;
+        if Tool::try_from_bytes(&asset_tool_info.data.borrow())?.collection.ne(collection_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        load_tool_asset(asset_info, collection_info, signer.key)?;
+
+        BurnV1CpiBuilder::new(mpl_core_program)
+            .asset(asset_info)
+            .collection(Some(collection_info))
+            .payer(signer)
+            .authority(Some(signer))
+            .system_program(Some(system_program))
+            .invoke()?;
+        close_pda(asset_tool_info, signer)?;
+    }
+
+    // Consume the upgrade recipe.
+    let recipe_data = recipe_info.data.borrow();
+    consume_ingredients(
+        Recipe::ingredients(&recipe_data)?,
+        ingredient_accounts,
+        signer,
+        treasury_info,
+        token_program,
+        associated_token_program,
+        system_program,
+    )?;
+
+    // Record the tool against the target collection's supply and wallet limit. Reforges are
+    // exempt from the target's fee and ingredient pricing, as the upgrade recipe is paid instead.
+    let mut target_config_data = target_config_info.data.borrow_mut();
+    let target_config = Config::try_from_bytes_mut(&mut target_config_data)?;
+    check_craft(target_config, signer, &args.proof, Clock::get()?.unix_timestamp)?;
+    target_config.minted = target_config.minted.saturating_add(1);
+    record_receipt(
+        target_receipt_info,
+        target_collection_info,
+        signer,
+        system_program,
+        target_config.wallet_limit,
+        args.receipt_bump,
+    )?;
+
+    let roll = rarity::base(target_config);
+
+    create_tool(
+        mint_info,
+        tool_info,
+        target_collection_info,
+        collection_authority,
+        signer,
+        mpl_core_program,
+        system_program,
+        target_config,
+        roll,
+        NO_RECIPE,
+        &[],
+        args.tool_bump,
+        args.collection_authority_bump,
+    )
+}
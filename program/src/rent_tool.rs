@@ -0,0 +1,70 @@
+use forge_api::{
+    consts::ROYALTY_CREATOR_ADDRESS,
+    error::ForgeError,
+    instruction::RentToolV1Args,
+    loaders::{load_program, load_rental, load_signer},
+    state::Rental,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+    system_instruction::transfer,
+    sysvar::Sysvar,
+};
+
+use crate::{tool::record_loadout, utils::AccountDeserialize};
+
+/// Rent a listed tool. The price goes to the owner, less the collection royalty, and the tool is
+/// added to the renter's loadout until the rental expires.
+pub fn process_rent_tool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: RentToolV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, owner_info, royalty_creator_info, rental_info, loadout_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    let rental = *Rental::try_from_bytes(&rental_info.data.borrow())?;
+    load_rental(rental_info, rental.asset, true)?;
+    if rental.is_rented() {
+        return Err(ForgeError::AlreadyRented.into());
+    }
+    if owner_info.key.ne(&rental.owner) || royalty_creator_info.key.ne(&ROYALTY_CREATOR_ADDRESS) {
+        return Err(ForgeError::InvalidRental.into());
+    }
+
+    // Pay the owner and the royalty.
+    let (owner_amount, royalty_amount) = rental.split();
+    if owner_amount.gt(&0) {
+        invoke(
+            &transfer(signer.key, owner_info.key, owner_amount),
+            &[signer.clone(), owner_info.clone(), system_program.clone()],
+        )?;
+    }
+    if royalty_amount.gt(&0) {
+        invoke(
+            &transfer(signer.key, royalty_creator_info.key, royalty_amount),
+            &[signer.clone(), royalty_creator_info.clone(), system_program.clone()],
+        )?;
+    }
+
+    // Start the rental.
+    let expires_slot = Clock::get()?.slot.saturating_add(rental.duration);
+    {
+        let mut rental_data = rental_info.data.borrow_mut();
+        let rental = Rental::try_from_bytes_mut(&mut rental_data)?;
+        rental.renter = *signer.key;
+        rental.expires_slot = expires_slot;
+    }
+
+    record_loadout(loadout_info, signer, system_program, rental.resource, &rental.asset, expires_slot, args.loadout_bump)
+}
@@ -0,0 +1,96 @@
+use forge_api::{
+    error::ForgeError,
+    instruction::RepairV1Args,
+    loaders::{load_active_config, load_program, load_recipe, load_signer, load_tool, load_treasury},
+    state::{Config, Recipe, Tool},
+};
+use mpl_core::types::Attribute;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    craft::consume_ingredients,
+    tool::{load_tool_asset, set_attributes},
+    utils::AccountDeserialize,
+};
+
+/// Restore durability to a worn tool by consuming the collection's repair recipe. Each repair
+/// restores the collection's repair durability, up to the durability the tool was minted with.
+pub fn process_repair<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: RepairV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 12 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, remaining_accounts) = accounts.split_at(12);
+    let [signer, asset_info, tool_info, collection_info, collection_authority, config_info, recipe_info, treasury_info, mpl_core_program, token_program, associated_token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_active_config(config_info, *collection_info.key, false)?;
+    load_tool(tool_info, *asset_info.key, true)?;
+    load_treasury(treasury_info, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(token_program, spl_token::ID)?;
+    load_program(associated_token_program, spl_associated_token_account::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    let asset = load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    // Check the collection repairs tools.
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if !config.is_repairable() {
+        return Err(ForgeError::NotRepairable.into());
+    }
+    load_recipe(recipe_info, *collection_info.key, config.repair_recipe, false)?;
+
+    // Restore durability.
+    let durability = {
+        let mut tool_data = tool_info.data.borrow_mut();
+        let tool = Tool::try_from_bytes_mut(&mut tool_data)?;
+        if tool.durability.ge(&tool.max_durability) {
+            return Err(ForgeError::NotRepairable.into());
+        }
+        tool.durability = tool
+            .durability
+            .saturating_add(config.repair_durability)
+            .min(tool.max_durability);
+        tool.durability
+    };
+
+    // Consume the repair recipe.
+    let recipe_data = recipe_info.data.borrow();
+    consume_ingredients(
+        Recipe::ingredients(&recipe_data)?,
+        remaining_accounts,
+        signer,
+        treasury_info,
+        token_program,
+        associated_token_program,
+        system_program,
+    )?;
+
+    // Sync the durability attribute of the asset.
+    set_attributes(
+        asset_info,
+        collection_info,
+        collection_authority,
+        signer,
+        mpl_core_program,
+        system_program,
+        &asset,
+        vec![Attribute {
+            key: "durability".to_string(),
+            value: durability.to_string(),
+        }],
+        args.collection_authority_bump,
+    )
+}
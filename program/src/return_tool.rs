@@ -0,0 +1,69 @@
+use forge_api::{
+    consts::RENTAL_SEED,
+    error::ForgeError,
+    instruction::ReturnToolV1Args,
+    loaders::{load_loadout, load_program, load_rental, load_signer},
+    state::Rental,
+};
+use mpl_core::instructions::TransferV1CpiBuilder;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    tool::clear_loadout,
+    utils::{close_pda, AccountDeserialize},
+};
+
+/// Return an escrowed tool to its owner, closing the listing. Anyone can return a tool once its
+/// rental has expired, and the owner can delist a tool that is not rented.
+pub fn process_return_tool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: ReturnToolV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, owner_info, asset_info, collection_info, rental_info, loadout_info, mpl_core_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_rental(rental_info, *asset_info.key, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    let rental = *Rental::try_from_bytes(&rental_info.data.borrow())?;
+    if owner_info.key.ne(&rental.owner) || collection_info.key.ne(&rental.collection) {
+        return Err(ForgeError::InvalidRental.into());
+    }
+
+    // Check the tool can be returned, and take it out of the renter's loadout.
+    let clock = Clock::get()?;
+    if rental.is_active(clock.slot) {
+        return Err(ForgeError::RentalActive.into());
+    }
+    if rental.is_rented() {
+        load_loadout(loadout_info, rental.renter, true)?;
+        clear_loadout(loadout_info, asset_info.key)?;
+    } else if signer.key.ne(&rental.owner) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Return the tool and close the listing.
+    let rental_seeds = &[RENTAL_SEED, asset_info.key.as_ref(), &[args.rental_bump]];
+    TransferV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(rental_info))
+        .new_owner(owner_info)
+        .system_program(Some(system_program))
+        .invoke_signed(&[rental_seeds])?;
+
+    close_pda(rental_info, owner_info)
+}
@@ -0,0 +1,77 @@
+use forge_api::{
+    error::ForgeError,
+    instruction::RevealCraftV1Args,
+    loaders::{load_commitment, load_config, load_program, load_signer, load_sysvar},
+    rarity,
+    state::{Commitment, Config},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::{self, Sysvar},
+};
+
+use crate::{craft::create_tool, utils::{close_pda, AccountDeserialize}};
+
+/// Mint the tool of a committed craft, with attributes rolled from the hash of the committed slot.
+pub fn process_reveal_craft<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: RevealCraftV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, mint_info, collection_info, collection_authority, config_info, commitment_info, tool_info, mpl_core_program, system_program, slot_hashes_sysvar] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_config(config_info, *collection_info.key, true)?;
+    load_commitment(commitment_info, *collection_info.key, *signer.key, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_sysvar(slot_hashes_sysvar, sysvar::slot_hashes::ID)?;
+
+    // Check the committed slot hash is available.
+    let clock = Clock::get()?;
+    let (slot_hash, recipe_id, escrow) = {
+        let commitment_data = commitment_info.data.borrow();
+        let commitment = Commitment::try_from_bytes(&commitment_data)?;
+        if commitment.is_expired(clock.slot) {
+            return Err(ForgeError::CommitmentExpired.into());
+        }
+        if clock.slot.le(&commitment.target_slot) {
+            return Err(ForgeError::CommitmentNotReady.into());
+        }
+        let slot_hash = rarity::slot_hash(&slot_hashes_sysvar.data.borrow(), commitment.target_slot)
+            .ok_or(ForgeError::CommitmentNotReady)?;
+        (slot_hash, commitment.recipe, commitment.escrow().to_vec())
+    };
+
+    // Close the commitment, returning its rent to the signer.
+    close_pda(commitment_info, signer)?;
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    config.commitments = config.commitments.saturating_sub(1);
+
+    // Roll the tool's attributes and mint it.
+    let roll = rarity::roll(config, &rarity::seed(&slot_hash, signer.key, commitment_info.key));
+
+    create_tool(
+        mint_info,
+        tool_info,
+        collection_info,
+        collection_authority,
+        signer,
+        mpl_core_program,
+        system_program,
+        config,
+        roll,
+        recipe_id,
+        &escrow,
+        args.tool_bump,
+        args.collection_authority_bump,
+    )
+}
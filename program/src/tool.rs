@@ -0,0 +1,179 @@
+use std::mem::size_of;
+
+use forge_api::{
+    consts::{COLLECTION_AUTHORITY_ADDRESS, COLLECTION_AUTHORITY_SEED, LOADOUT_SEED, MAX_NAME_LEN},
+    error::ForgeError,
+    loaders::{load_loadout, load_uninitialized_pda},
+    state::Loadout,
+};
+use mpl_core::{
+    instructions::{AddPluginV1CpiBuilder, RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{Attribute, Attributes, Plugin, PluginAuthority, PluginType, UpdateAuthority},
+    Asset,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::utils::{create_pda, AccountDeserialize, Discriminator};
+
+/// Checks the asset is in the collection and owned by the given wallet, and returns it.
+pub(crate) fn load_tool_asset(
+    asset_info: &AccountInfo,
+    collection_info: &AccountInfo,
+    owner: &Pubkey,
+) -> Result<Box<Asset>, ProgramError> {
+    if asset_info.owner.ne(&mpl_core::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let asset = Asset::from_bytes(&asset_info.data.borrow()).or(Err(ProgramError::InvalidAccountData))?;
+    if asset.base.update_authority.ne(&UpdateAuthority::Collection(*collection_info.key)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if asset.base.owner.ne(owner) {
+        return Err(ForgeError::AuthFailed.into());
+    }
+
+    Ok(asset)
+}
+
+/// Records a tool for its resource in the wallet's loadout, usable until the expiry slot if it
+/// is rented. The loadout is created on the wallet's first tool.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn record_loadout<'info>(
+    loadout_info: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    resource: [u8; MAX_NAME_LEN],
+    asset: &Pubkey,
+    expires_slot: u64,
+    loadout_bump: u8,
+) -> ProgramResult {
+    if loadout_info.data_is_empty() {
+        load_uninitialized_pda(
+            loadout_info,
+            &[LOADOUT_SEED, authority.key.as_ref()],
+            loadout_bump,
+            &forge_api::id(),
+        )?;
+        create_pda(
+            loadout_info,
+            &forge_api::id(),
+            8 + size_of::<Loadout>(),
+            &[LOADOUT_SEED, authority.key.as_ref(), &[loadout_bump]],
+            system_program,
+            authority,
+        )?;
+        let mut loadout_data = loadout_info.data.borrow_mut();
+        loadout_data[0] = Loadout::discriminator() as u8;
+        Loadout::try_from_bytes_mut(&mut loadout_data)?.authority = *authority.key;
+    } else {
+        load_loadout(loadout_info, *authority.key, true)?;
+    }
+
+    let mut loadout_data = loadout_info.data.borrow_mut();
+    let loadout = Loadout::try_from_bytes_mut(&mut loadout_data)?;
+    if loadout.tool(&resource, Clock::get()?.slot).is_some() {
+        return Err(ForgeError::ResourceEquipped.into());
+    }
+    let slot = loadout
+        .slots
+        .iter_mut()
+        .find(|slot| slot.is_empty())
+        .ok_or(ForgeError::LoadoutFull)?;
+    slot.resource = resource;
+    slot.asset = *asset;
+    slot.expires_slot = expires_slot;
+
+    Ok(())
+}
+
+/// Removes a tool from a loadout.
+pub(crate) fn clear_loadout(loadout_info: &AccountInfo, asset: &Pubkey) -> ProgramResult {
+    let mut loadout_data = loadout_info.data.borrow_mut();
+    let loadout = Loadout::try_from_bytes_mut(&mut loadout_data)?;
+    let slot = loadout
+        .slots
+        .iter_mut()
+        .find(|slot| slot.asset.eq(asset))
+        .ok_or(ForgeError::NotEquipped)?;
+    slot.clear();
+
+    Ok(())
+}
+
+/// Sets attributes of a tool asset, keeping the others. Attributes the asset does not have yet
+/// are appended. If the attributes are held by another authority, as on tools minted before the
+/// collection authority held them, the plugin is removed and added back under the collection
+/// authority, which may remove it as the update authority of the collection.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn set_attributes<'info>(
+    asset_info: &AccountInfo<'info>,
+    collection_info: &AccountInfo<'info>,
+    collection_authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    asset: &Asset,
+    attributes: Vec<Attribute>,
+    collection_authority_bump: u8,
+) -> ProgramResult {
+    let mut attribute_list = asset
+        .plugin_list
+        .attributes
+        .as_ref()
+        .map(|plugin| plugin.attributes.attribute_list.clone())
+        .unwrap_or_default();
+    for attribute in attributes {
+        match attribute_list.iter_mut().find(|a| a.key.eq(&attribute.key)) {
+            Some(existing) => existing.value = attribute.value,
+            None => attribute_list.push(attribute),
+        }
+    }
+
+    let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[collection_authority_bump]];
+
+    let held = asset.plugin_list.attributes.as_ref().map(|plugin| {
+        plugin.base.authority.eq(&PluginAuthority::Address {
+            address: COLLECTION_AUTHORITY_ADDRESS,
+        })
+    });
+    if held.eq(&Some(false)) {
+        RemovePluginV1CpiBuilder::new(mpl_core_program)
+            .asset(asset_info)
+            .collection(Some(collection_info))
+            .payer(payer)
+            .authority(Some(collection_authority))
+            .system_program(system_program)
+            .plugin_type(PluginType::Attributes)
+            .invoke_signed(&[collection_authority_seeds])?;
+    }
+    if held.ne(&Some(true)) {
+        return AddPluginV1CpiBuilder::new(mpl_core_program)
+            .asset(asset_info)
+            .collection(Some(collection_info))
+            .payer(payer)
+            .authority(Some(collection_authority))
+            .system_program(system_program)
+            .plugin(Plugin::Attributes(Attributes { attribute_list }))
+            .init_authority(PluginAuthority::Address {
+                address: COLLECTION_AUTHORITY_ADDRESS,
+            })
+            .invoke_signed(&[collection_authority_seeds]);
+    }
+
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(payer)
+        .authority(Some(collection_authority))
+        .system_program(system_program)
+        .plugin(Plugin::Attributes(Attributes { attribute_list }))
+        .invoke_signed(&[collection_authority_seeds])
+}
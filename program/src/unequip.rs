@@ -0,0 +1,58 @@
+use forge_api::{
+    consts::COLLECTION_AUTHORITY_SEED,
+    instruction::UnequipV1Args,
+    loaders::{load_loadout, load_program, load_signer},
+};
+use mpl_core::{
+    instructions::{RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{FreezeDelegate, Plugin, PluginType},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::tool::{clear_loadout, load_tool_asset};
+
+/// Unequip a tool from the signer's loadout, thawing it.
+pub fn process_unequip<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: UnequipV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, collection_info, collection_authority, loadout_info, mpl_core_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_loadout(loadout_info, *signer.key, true)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+    load_tool_asset(asset_info, collection_info, signer.key)?;
+
+    // Clear the tool's slot.
+    clear_loadout(loadout_info, asset_info.key)?;
+
+    // Thaw the tool and drop the freeze delegate.
+    let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[args.collection_authority_bump]];
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(collection_authority))
+        .system_program(system_program)
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .invoke_signed(&[collection_authority_seeds])?;
+
+    RemovePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(signer))
+        .system_program(system_program)
+        .plugin_type(PluginType::FreezeDelegate)
+        .invoke()
+}
@@ -0,0 +1,46 @@
+use forge_api::{
+    consts::{COLLECTION_AUTHORITY_SEED, INITIALIZER_ADDRESS},
+    instruction::UnfreezeV1Args,
+    loaders::{load_program, load_signer},
+};
+use mpl_core::{
+    instructions::UpdatePluginV1CpiBuilder,
+    types::{PermanentFreezeDelegate, Plugin},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+/// Unfreeze a soulbound tool so it can be moved, for support cases.
+pub fn process_unfreeze<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: UnfreezeV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, asset_info, collection_info, collection_authority, mpl_core_program, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_program(mpl_core_program, mpl_core::ID)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let collection_authority_seeds = &[COLLECTION_AUTHORITY_SEED, &[args.collection_authority_bump]];
+
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset_info)
+        .collection(Some(collection_info))
+        .payer(signer)
+        .authority(Some(collection_authority))
+        .system_program(system_program)
+        .plugin(Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }))
+        .invoke_signed(&[collection_authority_seeds])
+}
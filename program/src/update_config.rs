@@ -0,0 +1,141 @@
+use forge_api::{
+    consts::{INITIALIZER_ADDRESS, MAX_BASIS_POINTS, MAX_UPGRADE_INPUTS, MIN_UPGRADE_INPUTS},
+    error::ForgeError,
+    instruction::UpdateConfigV1Args,
+    loaders::{load_config, load_signer},
+    state::Config,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::utils::{string_to_bytes, AccountDeserialize};
+
+/// Update the tool metadata of an existing collection. The collection and its minted assets
+/// are left untouched.
+pub fn process_update_config<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: UpdateConfigV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, collection_info, config_info] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_config(config_info, *collection_info.key, true)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Update tool metadata.
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if let Some(multiplier) = args.multiplier {
+        config.multiplier = multiplier;
+    }
+    if let Some(durability) = args.durability {
+        config.durability = durability;
+    }
+    if let Some(name) = args.name {
+        config.name = string_to_bytes(&name).ok_or(ForgeError::InvalidMetadata)?;
+    }
+    if let Some(uri) = args.uri {
+        config.uri = string_to_bytes(&uri).ok_or(ForgeError::InvalidMetadata)?;
+    }
+    if let Some(rarity) = args.rarity {
+        config.rarity = string_to_bytes(&rarity).ok_or(ForgeError::InvalidMetadata)?;
+    }
+    if let Some(resource) = args.resource {
+        if resource.is_empty() {
+            return Err(ForgeError::InvalidResource.into());
+        }
+        config.resource = string_to_bytes(&resource).ok_or(ForgeError::InvalidResource)?;
+    }
+    if let Some(max_supply) = args.max_supply {
+        config.max_supply = max_supply;
+    }
+    if let Some(start_at) = args.start_at {
+        config.start_at = start_at;
+    }
+    if let Some(end_at) = args.end_at {
+        config.end_at = end_at;
+    }
+    if let Some(wallet_limit) = args.wallet_limit {
+        config.wallet_limit = wallet_limit;
+    }
+    if let Some(merkle_root) = args.merkle_root {
+        config.merkle_root = merkle_root;
+    }
+    if let Some(fee) = args.fee {
+        config.fee = fee;
+    }
+    if let Some(curve) = args.curve {
+        config.curve = u8::from(curve) as u64;
+    }
+    if let Some(curve_rate) = args.curve_rate {
+        config.curve_rate = curve_rate;
+    }
+    if let Some(rarity_tiers) = args.rarity_tiers {
+        config.set_rarity_tiers(&rarity_tiers)?;
+    }
+    if let Some(repair_recipe) = args.repair_recipe {
+        config.repair_recipe = repair_recipe;
+    }
+    if let Some(repair_durability) = args.repair_durability {
+        config.repair_durability = repair_durability;
+    }
+    if let Some(salvage_basis_points) = args.salvage_basis_points {
+        if salvage_basis_points.gt(&MAX_BASIS_POINTS) {
+            return Err(ForgeError::InvalidConfig.into());
+        }
+        config.salvage_basis_points = salvage_basis_points;
+    }
+    if let Some(upgrade_collection) = args.upgrade_collection {
+        config.upgrade_collection = upgrade_collection;
+    }
+    if let Some(upgrade_inputs) = args.upgrade_inputs {
+        config.upgrade_inputs = upgrade_inputs;
+    }
+    if let Some(upgrade_recipe) = args.upgrade_recipe {
+        config.upgrade_recipe = upgrade_recipe;
+    }
+    if let Some(enchant_slots) = args.enchant_slots {
+        config.enchant_slots = enchant_slots;
+    }
+    if let Some(enchantments) = args.enchantments {
+        config.set_enchantments(&enchantments)?;
+    }
+    if let Some(soulbound) = args.soulbound {
+        config.soulbound = soulbound as u64;
+    }
+    if let Some(reap_reward) = args.reap_reward {
+        config.reap_reward = reap_reward;
+    }
+    if config.start_at.gt(&0) && config.end_at.gt(&0) && config.end_at.le(&config.start_at) {
+        return Err(ForgeError::InvalidWindow.into());
+    }
+
+    // The base recipe stays craftable, and the repair and upgrade recipes must exist.
+    if config.is_repairable() && (config.repair_recipe.eq(&0) || config.repair_recipe.ge(&config.recipes)) {
+        return Err(ForgeError::InvalidRecipe.into());
+    }
+    if config.is_upgradeable() {
+        if config.upgrade_recipe.eq(&0) || config.upgrade_recipe.ge(&config.recipes) {
+            return Err(ForgeError::InvalidRecipe.into());
+        }
+        if config.upgrade_inputs.lt(&MIN_UPGRADE_INPUTS)
+            || config.upgrade_inputs.gt(&MAX_UPGRADE_INPUTS)
+            || config.upgrade_collection.eq(collection_info.key)
+        {
+            return Err(ForgeError::InvalidUpgrade.into());
+        }
+    }
+
+    Ok(())
+}
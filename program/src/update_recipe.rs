@@ -0,0 +1,54 @@
+use forge_api::{
+    consts::INITIALIZER_ADDRESS,
+    instruction::UpdateRecipeV1Args,
+    loaders::{load_program, load_recipe, load_signer},
+    state::Recipe,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    new_recipe::{validate_ingredients, write_ingredients},
+    utils::realloc_pda,
+};
+
+/// Update the ingredients of an existing recipe. The collection and its minted assets are left untouched.
+pub fn process_update_recipe<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: UpdateRecipeV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 4 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, additional_accounts) = accounts.split_at(4);
+    let [signer, collection_info, recipe_info, system_program] = required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+    load_recipe(recipe_info, *collection_info.key, args.id, true)?;
+    load_program(system_program, solana_program::system_program::ID)?;
+
+    // Check signer.
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    validate_ingredients(&args.ingredients, additional_accounts)?;
+
+    // Resize recipe to fit the new ingredients.
+    realloc_pda(
+        recipe_info,
+        Recipe::size(args.ingredients.len()),
+        system_program,
+        signer,
+    )?;
+    write_ingredients(recipe_info, &args.ingredients)?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use forge_api::{
+    consts::COAL_TOOL_AUTHORITY,
+    error::ForgeError,
+    instruction::UseToolV1Args,
+    loaders::{load_loadout, load_signer, load_tool},
+    state::{Loadout, Tool},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::utils::AccountDeserialize;
+
+/// Wear a tool down by the given amount of durability. Invoked by the COAL program when mining,
+/// and returns the tool's multiplier as little-endian u64 return data. The tool must be in the
+/// miner's loadout, and its rental must not have expired if it is rented.
+pub fn process_use_tool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    args: UseToolV1Args,
+) -> ProgramResult {
+    // Load accounts.
+    let [signer, authority_info, loadout_info, tool_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer)?;
+
+    // Check signer.
+    if signer.key.ne(&COAL_TOOL_AUTHORITY) {
+        return Err(ForgeError::AuthFailed.into());
+    }
+
+    let asset = Tool::try_from_bytes(&tool_info.data.borrow())?.asset;
+    load_tool(tool_info, asset, true)?;
+    load_loadout(loadout_info, *authority_info.key, false)?;
+
+    // Check the miner may use the tool.
+    let clock = Clock::get()?;
+    let loadout = *Loadout::try_from_bytes(&loadout_info.data.borrow())?;
+    let slot = loadout.slot(&asset).ok_or(ForgeError::NotEquipped)?;
+    if slot.is_expired(clock.slot) {
+        return Err(ForgeError::RentalExpired.into());
+    }
+
+    // Wear the tool down.
+    let mut tool_data = tool_info.data.borrow_mut();
+    let tool = Tool::try_from_bytes_mut(&mut tool_data)?;
+    if tool.is_broken() {
+        return Err(ForgeError::ToolBroken.into());
+    }
+    tool.durability = tool.durability.saturating_sub(args.amount);
+
+    set_return_data(&tool.multiplier.to_le_bytes());
+
+    Ok(())
+}
@@ -74,6 +74,78 @@ pub fn create_pda<'a, 'info>(
     Ok(())
 }
 
+/// Resizes a program-owned pda, topping up or refunding rent to the payer.
+#[inline(always)]
+pub fn realloc_pda<'a, 'info>(
+    target_account: &'a AccountInfo<'info>,
+    space: usize,
+    system_program: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let rent_exempt_balance = rent.minimum_balance(space);
+    let current_balance = target_account.lamports();
+
+    if rent_exempt_balance.gt(&current_balance) {
+        // If growing, transfer the missing rent from the payer
+        solana_program::program::invoke(
+            &solana_program::system_instruction::transfer(
+                payer.key,
+                target_account.key,
+                rent_exempt_balance - current_balance,
+            ),
+            &[
+                payer.clone(),
+                target_account.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    } else if current_balance.gt(&rent_exempt_balance) {
+        // If shrinking, refund the excess rent to the payer
+        let excess = current_balance - rent_exempt_balance;
+        **target_account.try_borrow_mut_lamports()? -= excess;
+        **payer.try_borrow_mut_lamports()? += excess;
+    }
+
+    target_account.realloc(space, true)
+}
+
+/// Closes a program-owned pda, moving its lamports to the destination and zeroing its data.
+#[inline(always)]
+pub fn close_pda<'info>(
+    target_account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> ProgramResult {
+    // Drain lamports
+    **destination.try_borrow_mut_lamports()? += target_account.lamports();
+    **target_account.try_borrow_mut_lamports()? = 0;
+
+    // Zero data and hand the account back to the system program
+    target_account.data.borrow_mut().fill(0);
+    target_account.realloc(0, false)?;
+    target_account.assign(&solana_program::system_program::ID);
+
+    Ok(())
+}
+
+/// Packs a string into a zero-padded fixed-size byte array. Returns None if the string does not fit.
+pub fn string_to_bytes<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        return None;
+    }
+
+    let mut buf = [0u8; N];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    Some(buf)
+}
+
+/// Unpacks a zero-padded fixed-size byte array into a string.
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
 pub trait Discriminator {
     fn discriminator() -> u8;
 }
@@ -122,6 +194,42 @@ macro_rules! impl_account_from_bytes {
     };
 }
 
+/// Like `impl_account_from_bytes`, but for accounts that store variable-length data after the
+/// struct. Only the leading bytes of the account are decoded.
+#[macro_export]
+macro_rules! impl_account_from_prefix_bytes {
+    ($struct_name:ident) => {
+        impl crate::utils::AccountDeserialize for $struct_name {
+            fn try_from_bytes(
+                data: &[u8],
+            ) -> Result<&Self, solana_program::program_error::ProgramError> {
+                if data.len() < 8 + core::mem::size_of::<Self>() {
+                    return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                if Self::discriminator().ne(&data[0]) {
+                    return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                bytemuck::try_from_bytes::<Self>(&data[8..8 + core::mem::size_of::<Self>()]).or(Err(
+                    solana_program::program_error::ProgramError::InvalidAccountData,
+                ))
+            }
+            fn try_from_bytes_mut(
+                data: &mut [u8],
+            ) -> Result<&mut Self, solana_program::program_error::ProgramError> {
+                if data.len() < 8 + core::mem::size_of::<Self>() {
+                    return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                if Self::discriminator().ne(&data[0]) {
+                    return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+                }
+                bytemuck::try_from_bytes_mut::<Self>(&mut data[8..8 + core::mem::size_of::<Self>()]).or(Err(
+                    solana_program::program_error::ProgramError::InvalidAccountData,
+                ))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_instruction_from_bytes {
     ($struct_name:ident) => {